@@ -0,0 +1,30 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use num_literal_traits::NumLiteralTrait;
+
+fn parse_decimal(c: &mut Criterion) {
+    c.bench_function("decimal", |b| b.iter(|| u64::parse_literal(black_box("1234567890"))));
+    c.bench_function("decimal signed", |b| b.iter(|| i64::parse_literal(black_box("+1234567890"))));
+    c.bench_function("decimal separated", |b| b.iter(|| u64::parse_literal(black_box("1_234_567_890"))));
+}
+
+fn parse_prefixed(c: &mut Criterion) {
+    c.bench_function("hexadecimal", |b| b.iter(|| u64::parse_literal(black_box("0x499602D2"))));
+    c.bench_function("binary", |b| b.iter(|| u32::parse_literal(black_box("0b1000_0001_1111_1010"))));
+}
+
+fn parse_scanned(c: &mut Criterion) {
+    c.bench_function("hexadecimal negative", |b| b.iter(|| i64::parse_literal(black_box("-0x_4996_02D2"))));
+    c.bench_function("octal", |b| b.iter(|| u32::parse_literal(black_box("0755"))));
+}
+
+criterion_group!(benches, parse_decimal, parse_prefixed, parse_scanned);
+criterion_main!(benches);
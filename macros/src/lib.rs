@@ -0,0 +1,83 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Procedural macros for the `num-literal-traits` crate, use them through
+//! its `macros` feature.
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, LitStr, Token, Type};
+use crate::literal::{check_int_literal, INTEGER_TYPES};
+
+mod literal;
+
+/// Macro input of the form `"literal" as type`.
+struct LiteralInput {
+    text: LitStr,
+    ty: Type,
+}
+
+impl Parse for LiteralInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let text = input.parse()?;
+        input.parse::<Token![as]>()?;
+        let ty = input.parse()?;
+        Ok(Self { text, ty })
+    }
+}
+
+/// The range of a type if it is one of the primitive integer types.
+fn integer_range(ty: &Type) -> Option<(i128, u128)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let ident = path.path.get_ident().filter(|_| path.qself.is_none())?;
+    INTEGER_TYPES.iter()
+        .find(|(name, _, _)| ident == name)
+        .map(|&(_, min, max)| (min, max))
+}
+
+/// Convert an integer literal to a number value while compiling.
+///
+/// The literal follows the rules of `parse_literal`, and the result is a
+/// constant of the given primitive integer type. An invalid literal or a
+/// value out of range for the type fails the expansion with the error
+/// message at the literal.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use num_literal_traits::parse_literal;
+///
+/// const MAGIC: u32 = parse_literal!("0xCAFE" as u32);
+/// assert_eq!(MAGIC, 0xCAFE);
+/// ```
+#[proc_macro]
+pub fn parse_literal(input: TokenStream) -> TokenStream {
+    let LiteralInput { text, ty } = parse_macro_input!(input as LiteralInput);
+    let Some((min, max)) = integer_range(&ty) else {
+        return syn::Error::new_spanned(ty, "expected a primitive integer type like `u32`")
+            .to_compile_error()
+            .into();
+    };
+    if let Err(message) = check_int_literal(&text.value(), min, max) {
+        return syn::Error::new(text.span(), message).to_compile_error().into();
+    }
+    let value = quote_spanned! {text.span()=>
+        ::num_literal_traits::__private::unwrap_int_literal(
+            ::num_literal_traits::__private::parse_int_literal(#text, <#ty>::MIN as i128, <#ty>::MAX as u128)
+        )
+    };
+    quote! {
+        {
+            const VALUE: #ty = #value as #ty;
+            VALUE
+        }
+    }
+    .into()
+}
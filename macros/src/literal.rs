@@ -0,0 +1,133 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// The integer types the macro can convert to, with their range. The
+/// range of `usize` and `isize` is that of the target, which is unknown
+/// here, so it is left to the expansion.
+pub(crate) const INTEGER_TYPES: [(&str, i128, u128); 12] = [
+    ("u8", 0, u8::MAX as u128),
+    ("u16", 0, u16::MAX as u128),
+    ("u32", 0, u32::MAX as u128),
+    ("u64", 0, u64::MAX as u128),
+    ("u128", 0, u128::MAX),
+    ("usize", 0, u128::MAX),
+    ("i8", i8::MIN as i128, i8::MAX as u128),
+    ("i16", i16::MIN as i128, i16::MAX as u128),
+    ("i32", i32::MIN as i128, i32::MAX as u128),
+    ("i64", i64::MIN as i128, i64::MAX as u128),
+    ("i128", i128::MIN, i128::MAX as u128),
+    ("isize", i128::MIN, i128::MAX as u128),
+];
+
+/// The message of a value that does not fit the range.
+const OUT_OF_RANGE: &str = "literal is out of range for the target type";
+
+/// Check that an integer literal has a value in `min..=max`, or return
+/// the message of the error.
+///
+/// This follows `parse_int_literal` of `num-literal-traits`, which this
+/// crate cannot depend on, and its error messages. The expansion still
+/// converts the literal with `parse_int_literal`, so the check only moves
+/// the errors to expansion time.
+pub(crate) fn check_int_literal(text: &str, min: i128, max: u128) -> Result<(), &'static str> {
+    let text = text.trim_ascii();
+    if let [b'\'', chr, b'\''] = text.as_bytes() {
+        return fit(false, u128::from(*chr), min, max);
+    }
+    let (negative, body) = match text.as_bytes() {
+        [b'-', body @ ..] => (true, body),
+        [b'+', body @ ..] => (false, body),
+        body => (false, body),
+    };
+    if body.len() < text.len() {
+        match body {
+            [] => return Err("sign without a number"),
+            [b'+' | b'-', ..] => return Err("invalid sign in literal"),
+            _ if text[1..].starts_with(char::is_whitespace) => return Err("invalid sign in literal"),
+            _ => {},
+        }
+    }
+    let suffix = INTEGER_TYPES.iter()
+        .filter(|&&(suffix, _, _)| body.len() > suffix.len() && body.ends_with(suffix.as_bytes()))
+        .find(|_| !matches!(body, [b'0', b'z' | b'Z', ..]));
+    let (body, min, max) = match suffix {
+        Some(&(suffix, suffix_min, suffix_max)) => {
+            (&body[..body.len() - suffix.len()], min.max(suffix_min), max.min(suffix_max))
+        },
+        None => (body, min, max),
+    };
+    let (digits, radix) = match body {
+        [b'0', b'b' | b'B', digits @ ..] => (digits, 2),
+        [b'0', b'o' | b'O', digits @ ..] => (digits, 8),
+        [b'0', b'x' | b'X' | b'h' | b'H', digits @ ..] => (digits, 16),
+        [b'0', b'z' | b'Z', digits @ ..] => (digits, 36),
+        [b'0', digits @ ..] if !digits.is_empty() => (digits, 8),
+        _ => (body, 10),
+    };
+    if digits.iter().find(|&&byte| byte != b'_').is_some_and(|&byte| byte == b'+' || byte == b'-') {
+        return Err("invalid sign in literal");
+    }
+    let mut value = Some(0u128);
+    let mut any_digit = false;
+    for &byte in digits.iter().filter(|&&byte| byte != b'_') {
+        let digit = char::from(byte).to_digit(radix).ok_or("invalid digit found in literal")?;
+        any_digit = true;
+        value = value.and_then(|value| value.checked_mul(u128::from(radix))?.checked_add(u128::from(digit)));
+    }
+    match value {
+        _ if !any_digit => Err("cannot parse literal from empty string"),
+        Some(value) => fit(negative, value, min, max),
+        None => Err(OUT_OF_RANGE),
+    }
+}
+
+/// Check that a signed magnitude lies in `min..=max`.
+fn fit(negative: bool, magnitude: u128, min: i128, max: u128) -> Result<(), &'static str> {
+    match negative {
+        true if min == 0 => Err("invalid sign in literal"),
+        true if magnitude > min.unsigned_abs() => Err(OUT_OF_RANGE),
+        false if magnitude > max => Err(OUT_OF_RANGE),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_literal_traits::__private::parse_int_literal;
+
+    const TEXTS: [&str; 55] = [
+        "0", "00", "123", " 42 ", "+7", "-7", "0xCAFE", "0XcafE", "0b1010", "0B_1_0", "0o777", "0755", "0zZ9",
+        "'A'", "1_000", "0x", "", "-", "+-1", "- 1", "0x-1", "CAFE", "08", "0b2", "0x1FF", "-0x80", "-0x81",
+        "-0", "__", "0x_", "0hFF", "0H", "0xFFu8", "0x100u8", "-1u8", "-0x80i8", "-0x81i8", "0u8", "017u16",
+        "0b1i128", "u8", "0xu8", "0zu8", "1_000u32", "1usize", "-1isize", "0xFFFF_FFFF_FFFFu64", "1u8u8", "'AB'",
+        "340282366920938463463374607431768211456", "-0x8000_0000_0000_0000_0000_0000_0000_0000", "0x1_0000i16",
+        "-\u{3000}7", "0x_-1", "_-1",
+    ];
+
+    /// Compare the check with `parse_int_literal`, which converts the
+    /// literal in the expansion, for every type of fixed width.
+    #[test]
+    fn check_matches_parse_int_literal() {
+        for &(name, min, max) in INTEGER_TYPES.iter().filter(|(name, _, _)| !name.ends_with("size")) {
+            for text in TEXTS {
+                let expected = parse_int_literal(text, min, max).map(|_| ()).map_err(|error| error.to_string());
+                assert_eq!(check_int_literal(text, min, max).map_err(str::to_string), expected, "{text:?} as {name}");
+            }
+        }
+    }
+
+    #[test]
+    fn check_leaves_size_range_to_expansion() {
+        let (_, min, max) = INTEGER_TYPES[5];
+        assert_eq!(check_int_literal("0x1_0000_0000_0000_0000", min, max), Ok(()));
+        assert_eq!(check_int_literal("-1", min, max), Err("invalid sign in literal"));
+        let (_, min, max) = INTEGER_TYPES[11];
+        assert_eq!(check_int_literal("-0x8000_0000_0000_0001", min, max), Ok(()));
+    }
+}
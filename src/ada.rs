@@ -0,0 +1,143 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::ParseLiteralError;
+use crate::parse_literal::{from_digits, split_sign};
+
+/// Check that separators only sit between digits, as Ada requires, and
+/// remove them.
+fn strip_separators(digits: &str) -> Result<String, ParseLiteralError> {
+    if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return Err(ParseLiteralError::MisplacedSeparator);
+    }
+    Ok(digits.replace("_", ""))
+}
+
+/// Read the exponent after the digits of a literal, like `E2` or `e+10`,
+/// which is 0 if there is none.
+fn parse_exponent(text: &str) -> Result<u32, ParseLiteralError> {
+    if text.is_empty() {
+        return Ok(0);
+    }
+    let digits = text.strip_prefix(['E', 'e']).ok_or(ParseLiteralError::InvalidExponent)?;
+    if digits.starts_with('-') {
+        return Err(ParseLiteralError::NotIntegral);
+    }
+    let digits = strip_separators(digits.strip_prefix('+').unwrap_or(digits))?;
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ParseLiteralError::InvalidExponent);
+    }
+    digits.parse().map_err(|_| ParseLiteralError::Overflow)
+}
+
+/// Convert an Ada numeric literal, like `16#FF#`, `2#1010#E2` or `1E6`,
+/// to a number value or return an error.
+///
+/// A based literal gives its base from 2 to 16 in decimal and its digits
+/// between two `#`, while a literal without `#` is decimal. Either may
+/// end with an exponent `E` (or `e`), scaling the value by a power of the
+/// base: `16#FF#E2` is `0xFF * 16^2`. Ada forbids negative exponents for
+/// integers, so they are a `NotIntegral` error, while any other malformed
+/// exponent is an `InvalidExponent` error. Separators `_` must sit
+/// between digits. A leading sign is accepted as a unary operator. The
+/// scaled value has to fit into 128 bits.
+///
+/// # Arguments
+/// - `text`: Ada numeric literal.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_ada_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_ada_literal::<u32>("16#FF#"), Ok(0xFF));
+/// assert_eq!(parse_ada_literal::<u32>("16#FF#E2"), Ok(0xFF00));
+/// assert_eq!(parse_ada_literal::<u32>("1E6"), Ok(1_000_000));
+/// assert_eq!(parse_ada_literal::<u32>("16#FF#E"), Err(ParseLiteralError::InvalidExponent));
+/// ```
+pub fn parse_ada_literal<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let (negative, text) = split_sign(text.trim())?;
+    let (radix, digits, exponent) = match text.split_once('#') {
+        Some((base, rest)) => {
+            let (digits, exponent) = rest.split_once('#').ok_or(ParseLiteralError::InvalidDigit)?;
+            let radix = from_digits::<u32>(false, &strip_separators(base)?, 10)
+                .ok()
+                .filter(|radix| (2..=16).contains(radix))
+                .ok_or(ParseLiteralError::InvalidRadix)?;
+            (radix, digits, exponent)
+        },
+        None => {
+            let (digits, exponent) = text.split_at(text.find(['E', 'e']).unwrap_or(text.len()));
+            (10, digits, exponent)
+        },
+    };
+    let mantissa = from_digits::<u128>(false, &strip_separators(digits)?, radix)?;
+    let exponent = parse_exponent(exponent)?;
+    let value = match mantissa {
+        0 => 0,
+        _ => u128::from(radix).checked_pow(exponent)
+            .and_then(|scale| mantissa.checked_mul(scale))
+            .ok_or(ParseLiteralError::Overflow)?,
+    };
+    from_digits(negative, &value.to_string(), 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ada_based_works() {
+        assert_eq!(parse_ada_literal::<u32>("16#FF#"), Ok(255));
+        assert_eq!(parse_ada_literal::<u32>("16#ff#"), Ok(255));
+        assert_eq!(parse_ada_literal::<u32>("2#1111_1111#"), Ok(255));
+        assert_eq!(parse_ada_literal::<u32>("8#377#"), Ok(255));
+        assert_eq!(parse_ada_literal::<i32>("-16#FF#"), Ok(-255));
+        assert_eq!(parse_ada_literal::<u32>("1_000"), Ok(1000));
+    }
+
+    #[test]
+    fn ada_exponent_works() {
+        assert_eq!(parse_ada_literal::<u32>("16#FF#E2"), Ok(0xFF00));
+        assert_eq!(parse_ada_literal::<u32>("16#FF#e+1"), Ok(0xFF0));
+        assert_eq!(parse_ada_literal::<u32>("2#101#E3"), Ok(0b101000));
+        assert_eq!(parse_ada_literal::<u32>("1E6"), Ok(1_000_000));
+        assert_eq!(parse_ada_literal::<u32>("12e0"), Ok(12));
+        assert_eq!(parse_ada_literal::<u32>("0E1_000_000"), Ok(0));
+    }
+
+    #[test]
+    fn ada_overflow_fails() {
+        assert_eq!(parse_ada_literal::<u16>("16#FF#E3"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_ada_literal::<u32>("16#1#E8"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_ada_literal::<u128>("2#1#E128"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_ada_literal::<u128>("1E99999999999"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn ada_malformed_exponent_fails() {
+        for text in ["16#FF#E", "16#FF#E+", "16#FF#X2", "16#FF#E2A", "1E", "1Ex"] {
+            assert_eq!(parse_ada_literal::<u32>(text), Err(ParseLiteralError::InvalidExponent), "{text}");
+        }
+        assert_eq!(parse_ada_literal::<u32>("16#FF#E-1"), Err(ParseLiteralError::NotIntegral));
+    }
+
+    #[test]
+    fn ada_malformed_fails() {
+        assert_eq!(parse_ada_literal::<u32>("17#FF#"), Err(ParseLiteralError::InvalidRadix));
+        assert_eq!(parse_ada_literal::<u32>("#FF#"), Err(ParseLiteralError::InvalidRadix));
+        assert_eq!(parse_ada_literal::<u32>("16#FF"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_ada_literal::<u32>("8#9#"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_ada_literal::<u32>("16##"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_ada_literal::<u32>("16#_FF#"), Err(ParseLiteralError::MisplacedSeparator));
+        assert_eq!(parse_ada_literal::<u32>("-16#FF#"), Err(ParseLiteralError::InvalidSign));
+    }
+}
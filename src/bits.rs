@@ -0,0 +1,479 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Num, PrimInt, Signed};
+use crate::{NumLiteralTrait, ParseLiteralError};
+use crate::constant::strip_rust_suffix;
+use crate::format::match_prefix;
+use crate::parse_literal::from_digits;
+use crate::LiteralFormat;
+
+/// Parse a literal as a raw bit pattern that fits the width of `T`.
+fn parse_bits<T: PrimInt>(text: &str) -> Result<(u128, u32), ParseLiteralError> {
+    let width = T::zero().count_zeros();
+    let bits = u128::parse_literal(text)?;
+    if width < u128::BITS && bits >> width != 0 {
+        return Err(ParseLiteralError::Overflow);
+    }
+    Ok((bits, width))
+}
+
+/// Convert a literal holding a sign-magnitude bit pattern to a number
+/// value or return an error.
+///
+/// The pattern is read at the full width of `T`: its top bit is the
+/// sign and the remaining bits are the magnitude. The number of digits
+/// written does not matter, so `"0b101"` is 5 for any type, and a
+/// pattern wider than `T` is an `Overflow` error. A set sign bit with a
+/// zero magnitude is negative zero, which is just 0 for integers.
+///
+/// # Arguments
+/// - `text`: Literal of the bit pattern, in any unsigned format.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_sign_magnitude;
+///
+/// assert_eq!(parse_sign_magnitude::<i8>("0b1000_0101"), Ok(-5));
+/// assert_eq!(parse_sign_magnitude::<i8>("0b0000_0101"), Ok(5));
+/// assert_eq!(parse_sign_magnitude::<i16>("0x8005"), Ok(-5));
+/// ```
+pub fn parse_sign_magnitude<T: PrimInt + Signed>(text: &str) -> Result<T, ParseLiteralError> {
+    let (bits, width) = parse_bits::<T>(text)?;
+    let sign = 1 << (width - 1);
+    let magnitude = T::from(bits & !sign).ok_or(ParseLiteralError::Overflow)?;
+    Ok(if bits & sign != 0 { -magnitude } else { magnitude })
+}
+
+/// Convert a literal holding a two's complement bit pattern to a number
+/// value or return an error.
+///
+/// The pattern is reinterpreted at the full width of `T`, like a cast
+/// from the unsigned type of the same width: `0xFFFFFFFF` is `-1` for an
+/// `i32`, as printed by `format!("{:#X}", -1i32)`. Like in
+/// `parse_sign_magnitude`, the number of digits written does not matter,
+/// so `0xFF` is 255 for an `i32`, and a pattern wider than `T` is an
+/// `Overflow` error.
+///
+/// # Arguments
+/// - `text`: Literal of the bit pattern, in any unsigned format.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_reinterpret_bits;
+///
+/// assert_eq!(parse_reinterpret_bits::<i32>("0xFFFFFFFF"), Ok(-1));
+/// assert_eq!(parse_reinterpret_bits::<i8>("0x80"), Ok(i8::MIN));
+/// assert_eq!(parse_reinterpret_bits::<i16>("0x7FFF"), Ok(i16::MAX));
+/// ```
+pub fn parse_reinterpret_bits<T: PrimInt + Signed>(text: &str) -> Result<T, ParseLiteralError> {
+    let (bits, width) = parse_bits::<T>(text)?;
+    let sign = 1 << (width - 1);
+    let value = T::from(bits & !sign).ok_or(ParseLiteralError::Overflow)?;
+    Ok(if bits & sign != 0 { T::min_value() + value } else { value })
+}
+
+/// Convert a literal holding a ones' complement bit pattern to a number
+/// value or return an error.
+///
+/// The pattern is read at the full width of `T`: with the top bit clear
+/// it is the value itself, with the top bit set it is a negative value
+/// whose magnitude is the inverse of all bits. Like in
+/// `parse_sign_magnitude`, the number of digits written does not matter
+/// and a pattern wider than `T` is an `Overflow` error. Ones' complement
+/// has two zeros, all bits clear and all bits set, and both convert to 0.
+///
+/// # Arguments
+/// - `text`: Literal of the bit pattern, in any unsigned format.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_ones_complement;
+///
+/// assert_eq!(parse_ones_complement::<i8>("0b1111_1110"), Ok(-1));
+/// assert_eq!(parse_ones_complement::<i8>("0b1111_1111"), Ok(0));
+/// assert_eq!(parse_ones_complement::<i16>("0xFFFA"), Ok(-5));
+/// ```
+pub fn parse_ones_complement<T: PrimInt + Signed>(text: &str) -> Result<T, ParseLiteralError> {
+    let (bits, width) = parse_bits::<T>(text)?;
+    let mask = u128::MAX >> (u128::BITS - width);
+    if bits >> (width - 1) == 0 {
+        return T::from(bits).ok_or(ParseLiteralError::Overflow);
+    }
+    let magnitude = T::from(mask ^ bits).ok_or(ParseLiteralError::Overflow)?;
+    Ok(-magnitude)
+}
+
+/// Convert a literal holding a Gray code to its natural binary value or
+/// return an error.
+///
+/// Each bit of the value is the XOR of all Gray code bits at and above
+/// its position. Gray codes are usually written in binary, but any
+/// unsigned literal format is accepted, and leading zeros do not change
+/// the result.
+///
+/// # Arguments
+/// - `text`: Literal of the Gray code, in any unsigned format.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_gray_literal;
+///
+/// assert_eq!(parse_gray_literal::<u8>("0b1100"), Ok(8));
+/// assert_eq!(parse_gray_literal::<u8>("0b0110"), Ok(4));
+/// ```
+pub fn parse_gray_literal<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let mut bits = u128::parse_literal(text)?;
+    let mut shift = 1;
+    while shift < u128::BITS {
+        bits ^= bits >> shift;
+        shift <<= 1;
+    }
+    from_digits(false, &bits.to_string(), 10)
+}
+
+/// Convert a literal with an optional bit-width annotation like
+/// `u12:4000` to a number value or return an error.
+///
+/// The annotation `u<width>:` or `i<width>:` declares an unsigned or
+/// signed two's complement value of 1 to 128 bits. The value must fit
+/// the declared width, not just `T`, so `u4:20` is an `Overflow` error
+/// even for a `u32`. A width outside 1 to 128 is an `InvalidWidth` error.
+/// Only a `u` or `i` with digits and then `:` is an annotation,
+/// so a char literal like `':'` is not. Without an annotation, this is
+/// just `parse_literal`.
+///
+/// # Arguments
+/// - `text`: Literal, optionally preceded by a width annotation.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_width_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_width_literal::<u16>("u12:4000"), Ok(4000));
+/// assert_eq!(parse_width_literal::<i8>("i4:-8"), Ok(-8));
+/// assert_eq!(parse_width_literal::<u32>("u4:20"), Err(ParseLiteralError::Overflow));
+/// ```
+pub fn parse_width_literal<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let annotated = text.trim_start();
+    let signed = annotated.starts_with('i');
+    let annotation = annotated.strip_prefix(['u', 'i']).and_then(|rest| {
+        let (width, value) = rest.split_at(rest.bytes().take_while(u8::is_ascii_digit).count());
+        Some((width, value.trim_start().strip_prefix(':')?)).filter(|_| !width.is_empty())
+    });
+    let Some((width, value)) = annotation else {
+        return T::parse_literal(text);
+    };
+    let width = match width.parse::<u32>() {
+        Ok(bits @ 1..=128) => bits,
+        _ => return Err(ParseLiteralError::InvalidWidth),
+    };
+    if signed {
+        let value = i128::parse_literal(value)?;
+        if width < i128::BITS && !(-1 << (width - 1)..1 << (width - 1)).contains(&value) {
+            return Err(ParseLiteralError::Overflow);
+        }
+        from_digits(value < 0, &value.unsigned_abs().to_string(), 10)
+    } else {
+        let value = u128::parse_literal(value)?;
+        if width < u128::BITS && value >> width != 0 {
+            return Err(ParseLiteralError::Overflow);
+        }
+        from_digits(false, &value.to_string(), 10)
+    }
+}
+
+/// Parse a hexadecimal or binary literal with exactly the digits of a
+/// `width` bit pattern.
+fn parse_exact_bits(text: &str, width: u32) -> Result<u128, ParseLiteralError> {
+    let bits = u128::parse_literal(text)?;
+    let text = strip_rust_suffix(text.trim()).0;
+    let digit_bits = match match_prefix(text) {
+        Some((_, LiteralFormat::Hexadecimal)) => 4,
+        Some((_, LiteralFormat::Binary)) => 1,
+        _ => return Err(ParseLiteralError::InvalidWidth),
+    };
+    let digits = text[2..].bytes().filter(|&byte| byte != b'_').count() as u32;
+    if digits * digit_bits != width {
+        return Err(ParseLiteralError::InvalidWidth);
+    }
+    Ok(bits)
+}
+
+/// Convert a literal holding the bits of an IEEE 754 double to a number
+/// value or return an error.
+///
+/// The literal is hexadecimal with exactly 16 digits or binary with
+/// exactly 64 digits, leading zeros included, like the output of
+/// `format!("{:#018X}", value.to_bits())`. Any other number of digits or
+/// any other format is an `InvalidWidth` error. The bits are then
+/// reinterpreted like `f64::from_bits`, so NaN payloads are kept.
+///
+/// # Arguments
+/// - `text`: Hexadecimal or binary literal of the 64 bits.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_f64_from_bits_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_f64_from_bits_literal("0x3FF0000000000000"), Ok(1.0));
+/// assert_eq!(parse_f64_from_bits_literal("0xC000_0000_0000_0000"), Ok(-2.0));
+/// assert_eq!(parse_f64_from_bits_literal("0x3FF0"), Err(ParseLiteralError::InvalidWidth));
+/// ```
+pub fn parse_f64_from_bits_literal(text: &str) -> Result<f64, ParseLiteralError> {
+    Ok(f64::from_bits(parse_exact_bits(text, u64::BITS)? as u64))
+}
+
+/// Convert a literal holding the bits of an IEEE 754 single to a number
+/// value or return an error.
+///
+/// Works like `parse_f64_from_bits_literal` for 32 bits: hexadecimal
+/// with exactly 8 digits or binary with exactly 32 digits.
+///
+/// # Arguments
+/// - `text`: Hexadecimal or binary literal of the 32 bits.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_f32_from_bits_literal;
+///
+/// assert_eq!(parse_f32_from_bits_literal("0x3F800000"), Ok(1.0));
+/// assert_eq!(parse_f32_from_bits_literal("0x40490FDB"), Ok(std::f32::consts::PI));
+/// ```
+pub fn parse_f32_from_bits_literal(text: &str) -> Result<f32, ParseLiteralError> {
+    Ok(f32::from_bits(parse_exact_bits(text, u32::BITS)? as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_magnitude_i8_works() {
+        assert_eq!(parse_sign_magnitude::<i8>("0b1000_0101"), Ok(-5));
+        assert_eq!(parse_sign_magnitude::<i8>("0b101"), Ok(5));
+        assert_eq!(parse_sign_magnitude::<i8>("0x7F"), Ok(127));
+        assert_eq!(parse_sign_magnitude::<i8>("0xFF"), Ok(-127));
+    }
+
+    #[test]
+    fn sign_magnitude_i16_works() {
+        assert_eq!(parse_sign_magnitude::<i16>("0b1000_0101"), Ok(133));
+        assert_eq!(parse_sign_magnitude::<i16>("0x8085"), Ok(-133));
+        assert_eq!(parse_sign_magnitude::<i16>("0xFFFF"), Ok(-32767));
+    }
+
+    #[test]
+    fn sign_magnitude_i128_works() {
+        assert_eq!(parse_sign_magnitude::<i128>(&format!("{:#x}", 1u128 << 127 | 7)), Ok(-7));
+    }
+
+    #[test]
+    fn sign_magnitude_negative_zero_is_zero() {
+        assert_eq!(parse_sign_magnitude::<i8>("0b1000_0000"), Ok(0));
+        assert_eq!(parse_sign_magnitude::<i16>("0x8000"), Ok(0));
+    }
+
+    #[test]
+    fn sign_magnitude_too_wide_fails() {
+        assert_eq!(parse_sign_magnitude::<i8>("0x100"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_sign_magnitude::<i16>("0x1_0000"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn reinterpret_bits_full_width_works() {
+        assert_eq!(parse_reinterpret_bits::<i8>("0xFF"), Ok(-1));
+        assert_eq!(parse_reinterpret_bits::<i8>("0xFE"), Ok(-2));
+        assert_eq!(parse_reinterpret_bits::<i16>("0xFFFF"), Ok(-1));
+        assert_eq!(parse_reinterpret_bits::<i16>("0x8000"), Ok(i16::MIN));
+        assert_eq!(parse_reinterpret_bits::<i32>("0xFFFFFFFF"), Ok(-1));
+        assert_eq!(parse_reinterpret_bits::<i32>("0x8000_0001"), Ok(i32::MIN + 1));
+        assert_eq!(parse_reinterpret_bits::<i128>(&format!("{:#x}", u128::MAX)), Ok(-1));
+    }
+
+    #[test]
+    fn reinterpret_bits_matches_cast() {
+        for value in [i32::MIN, -65536, -1, 0, 1, 0xCAFE, i32::MAX] {
+            assert_eq!(parse_reinterpret_bits::<i32>(&format!("{value:#X}")), Ok(value));
+            assert_eq!(parse_reinterpret_bits::<i32>(&format!("{value:#b}")), Ok(value));
+        }
+    }
+
+    #[test]
+    fn reinterpret_bits_narrow_is_positive() {
+        assert_eq!(parse_reinterpret_bits::<i16>("0xFF"), Ok(255));
+        assert_eq!(parse_reinterpret_bits::<i32>("0xFFFF"), Ok(65535));
+    }
+
+    #[test]
+    fn reinterpret_bits_too_wide_fails() {
+        assert_eq!(parse_reinterpret_bits::<i8>("0x1FF"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_reinterpret_bits::<i32>("0x1_0000_0000"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn ones_complement_i8_works() {
+        assert_eq!(parse_ones_complement::<i8>("0b1111_1110"), Ok(-1));
+        assert_eq!(parse_ones_complement::<i8>("0b1000_0000"), Ok(-127));
+        assert_eq!(parse_ones_complement::<i8>("0b0111_1111"), Ok(127));
+        assert_eq!(parse_ones_complement::<i8>("0b101"), Ok(5));
+    }
+
+    #[test]
+    fn ones_complement_i16_works() {
+        assert_eq!(parse_ones_complement::<i16>("0b1111_1110"), Ok(254));
+        assert_eq!(parse_ones_complement::<i16>("0xFFFE"), Ok(-1));
+        assert_eq!(parse_ones_complement::<i16>("0x8000"), Ok(-32767));
+    }
+
+    #[test]
+    fn ones_complement_i128_works() {
+        assert_eq!(parse_ones_complement::<i128>(&format!("{:#x}", u128::MAX - 7)), Ok(-7));
+    }
+
+    #[test]
+    fn ones_complement_both_zeros_are_zero() {
+        assert_eq!(parse_ones_complement::<i8>("0x00"), Ok(0));
+        assert_eq!(parse_ones_complement::<i8>("0xFF"), Ok(0));
+        assert_eq!(parse_ones_complement::<i16>("0xFFFF"), Ok(0));
+    }
+
+    #[test]
+    fn ones_complement_too_wide_fails() {
+        assert_eq!(parse_ones_complement::<i8>("0x1FF"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn gray_works() {
+        let cases = [("0b0", 0), ("0b1", 1), ("0b11", 2), ("0b10", 3), ("0b110", 4), ("0b1100", 8), ("0b1000", 15)];
+        for (text, value) in cases {
+            assert_eq!(parse_gray_literal::<u8>(text), Ok(value), "{text}");
+        }
+    }
+
+    #[test]
+    fn gray_round_trip_works() {
+        for value in 0..=u16::MAX {
+            let gray = value ^ (value >> 1);
+            assert_eq!(parse_gray_literal::<u16>(&format!("{gray:#b}")), Ok(value));
+        }
+    }
+
+    #[test]
+    fn gray_overflow_fails() {
+        assert_eq!(parse_gray_literal::<u8>("0b1_0000_0000"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_gray_literal::<u128>(&format!("{:#b}", 1u128 << 127)), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn width_fitting_works() {
+        assert_eq!(parse_width_literal::<u16>("u12:4000"), Ok(4000));
+        assert_eq!(parse_width_literal::<u16>("u12:0xFFF"), Ok(4095));
+        assert_eq!(parse_width_literal::<u8>("u1:1"), Ok(1));
+        assert_eq!(parse_width_literal::<i8>("i4:7"), Ok(7));
+        assert_eq!(parse_width_literal::<i8>("i4:-8"), Ok(-8));
+        assert_eq!(parse_width_literal::<i32>(" i12 : -0x800 "), Ok(-2048));
+        assert_eq!(parse_width_literal::<u128>(&format!("u128:{}", u128::MAX)), Ok(u128::MAX));
+        assert_eq!(parse_width_literal::<i128>(&format!("i128:{}", i128::MIN)), Ok(i128::MIN));
+        assert_eq!(parse_width_literal::<u8>("0x2A"), Ok(42));
+    }
+
+    #[test]
+    fn width_not_fitting_fails() {
+        assert_eq!(parse_width_literal::<u32>("u4:20"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_width_literal::<u32>("u12:4096"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_width_literal::<i32>("i4:8"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_width_literal::<i32>("i4:-9"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_width_literal::<u32>("u4:-1"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn width_not_fitting_storage_fails() {
+        assert_eq!(parse_width_literal::<u8>("u12:4000"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_width_literal::<u8>("i8:-1"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn width_malformed_fails() {
+        for text in ["u0:0", "u129:1", "i00:1", "u99999999999:1"] {
+            assert_eq!(parse_width_literal::<u32>(text), Err(ParseLiteralError::InvalidWidth), "{text}");
+        }
+        assert_eq!(parse_width_literal::<u32>("u8:"), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn width_without_annotation_works() {
+        assert_eq!(parse_width_literal::<u8>("':'"), Ok(58));
+        assert_eq!(parse_width_literal::<u8>(" 0x3A "), Ok(58));
+        for text in ["u:1", "x8:1", "u+8:1", "8:1", ":1"] {
+            assert_eq!(parse_width_literal::<u32>(text), Err(ParseLiteralError::InvalidDigit), "{text}");
+        }
+    }
+
+    #[test]
+    fn f64_from_bits_works() {
+        assert_eq!(parse_f64_from_bits_literal("0x3FF0000000000000"), Ok(1.0));
+        assert_eq!(parse_f64_from_bits_literal("0x0000000000000000"), Ok(0.0));
+        assert_eq!(parse_f64_from_bits_literal("0x400921FB54442D18"), Ok(std::f64::consts::PI));
+        assert_eq!(parse_f64_from_bits_literal(" 0X7FF0000000000000 "), Ok(f64::INFINITY));
+        assert_eq!(parse_f64_from_bits_literal("0xFFEFFFFFFFFFFFFF"), Ok(f64::MIN));
+        assert!(parse_f64_from_bits_literal("0x7FF8000000000000").unwrap().is_nan());
+        let negative_zero = parse_f64_from_bits_literal("0x8000000000000000").unwrap();
+        assert!(negative_zero == 0.0 && negative_zero.is_sign_negative());
+        let binary = format!("{:#066b}", 1.5f64.to_bits());
+        assert_eq!(parse_f64_from_bits_literal(&binary), Ok(1.5));
+        assert_eq!(parse_f64_from_bits_literal("0x3FF0000000000000u64"), Ok(1.0));
+    }
+
+    #[test]
+    fn f32_from_bits_works() {
+        assert_eq!(parse_f32_from_bits_literal("0x3F800000"), Ok(1.0));
+        assert_eq!(parse_f32_from_bits_literal("0xBF80_0000"), Ok(-1.0));
+        assert_eq!(parse_f32_from_bits_literal("0x00000001"), Ok(f32::from_bits(1)));
+        assert_eq!(parse_f32_from_bits_literal("0x7F800000"), Ok(f32::INFINITY));
+        let binary = format!("{:#034b}", 0.1f32.to_bits());
+        assert_eq!(parse_f32_from_bits_literal(&binary), Ok(0.1));
+    }
+
+    #[test]
+    fn from_bits_wrong_width_fails() {
+        for text in ["0x3FF", "0x03FF0000000000000", "0x1", "1065353216", "0o7740000000", "0z1", "0x3FFu32"] {
+            assert_eq!(parse_f32_from_bits_literal(text), Err(ParseLiteralError::InvalidWidth), "{text}");
+        }
+        assert_eq!(parse_f64_from_bits_literal("0x3F800000"), Err(ParseLiteralError::InvalidWidth));
+        assert_eq!(parse_f32_from_bits_literal("0x3FF0000000000000"), Err(ParseLiteralError::InvalidWidth));
+        assert_eq!(parse_f32_from_bits_literal("-0x3F800000"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(parse_f32_from_bits_literal("0x3F80000G"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn sign_magnitude_signed_text_fails() {
+        assert_eq!(parse_sign_magnitude::<i8>("-0b101"), Err(ParseLiteralError::InvalidSign));
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::{NumLiteralTrait, ParseLiteralError};
+use crate::parse_literal::from_digits;
+
+/// The order of the bytes making up a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// The most significant byte first, as in network byte order.
+    Big,
+    /// The least significant byte first.
+    Little,
+}
+
+/// Convert colon-separated hex bytes like `DE:AD:BE:EF` to a number
+/// value or return an error.
+///
+/// Every group must be exactly two hex digits, in either case. An empty
+/// group is an `Empty` error, a group of another length is an
+/// `IncompleteByte` error and any other character, including a sign, is
+/// an `InvalidDigit` error. The bytes are assembled in the given byte
+/// order, and a value that does not fit `T` is an `Overflow` error.
+///
+/// # Arguments
+/// - `text`: Hex bytes, separated by `:`.
+/// - `endian`: Order of the bytes in the text.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_colon_hex, Endian};
+///
+/// assert_eq!(parse_colon_hex::<u32>("DE:AD:BE:EF", Endian::Big), Ok(0xDEADBEEF));
+/// assert_eq!(parse_colon_hex::<u32>("DE:AD:BE:EF", Endian::Little), Ok(0xEFBEADDE));
+/// ```
+pub fn parse_colon_hex<T: Num>(text: &str, endian: Endian) -> Result<T, ParseLiteralError> {
+    let mut groups: Vec<&str> = text.trim().split(':').collect();
+    for group in &groups {
+        match group.len() {
+            0 => return Err(ParseLiteralError::Empty),
+            2 => {},
+            _ => return Err(ParseLiteralError::IncompleteByte),
+        }
+        if !group.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(ParseLiteralError::InvalidDigit);
+        }
+    }
+    if endian == Endian::Little {
+        groups.reverse();
+    }
+    from_digits(false, &groups.concat(), 16)
+}
+
+/// Convert a literal to its bytes at a fixed width, or return an error.
+///
+/// The literal is parsed like `parse_literal` and written as `width`
+/// bytes, from 1 to 16, in the given byte order. A smaller value is
+/// padded with zero bytes, while a value that needs more bytes is an
+/// `Overflow` error. A negative value is written in two's complement and
+/// must fit the signed range of the width, so `-1` is `[0xFF, 0xFF]` at
+/// two bytes and `-0x8001` is an `Overflow` error there. A width of 0 or
+/// above 16 is an `InvalidWidth` error.
+///
+/// # Arguments
+/// - `text`: Textual representation of an integer.
+/// - `width`: Number of bytes to write.
+/// - `endian`: Order of the bytes in the result.
+/// # Returns
+/// - The bytes of the value or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_to_bytes, Endian, ParseLiteralError};
+///
+/// assert_eq!(parse_to_bytes("0xCAFE", 4, Endian::Big), Ok(vec![0x00, 0x00, 0xCA, 0xFE]));
+/// assert_eq!(parse_to_bytes("0xCAFE", 2, Endian::Little), Ok(vec![0xFE, 0xCA]));
+/// assert_eq!(parse_to_bytes("0x1_0000", 2, Endian::Big), Err(ParseLiteralError::Overflow));
+/// ```
+pub fn parse_to_bytes(text: &str, width: usize, endian: Endian) -> Result<Vec<u8>, ParseLiteralError> {
+    if !(1..=16).contains(&width) {
+        return Err(ParseLiteralError::InvalidWidth);
+    }
+    let bits = width as u32 * 8;
+    let value = if text.trim_start().starts_with('-') {
+        let value = i128::parse_literal(text)?;
+        if bits < i128::BITS && value < -1 << (bits - 1) {
+            return Err(ParseLiteralError::Overflow);
+        }
+        value as u128
+    } else {
+        let value = u128::parse_literal(text)?;
+        if bits < u128::BITS && value >> bits != 0 {
+            return Err(ParseLiteralError::Overflow);
+        }
+        value
+    };
+    let bytes = value.to_be_bytes();
+    let mut bytes = bytes[bytes.len() - width..].to_vec();
+    if endian == Endian::Little {
+        bytes.reverse();
+    }
+    Ok(bytes)
+}
+
+/// The XOR of all bytes, a checksum for `parse_checked_hex`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::xor_checksum;
+///
+/// assert_eq!(xor_checksum(&[0x12, 0x34]), 0x26);
+/// ```
+pub fn xor_checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |checksum, byte| checksum ^ byte)
+}
+
+/// The sum of all bytes modulo 256, a checksum for `parse_checked_hex`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::sum_checksum;
+///
+/// assert_eq!(sum_checksum(&[0x12, 0x34]), 0x46);
+/// assert_eq!(sum_checksum(&[0xFF, 0x02]), 0x01);
+/// ```
+pub fn sum_checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |checksum, &byte| checksum.wrapping_add(byte))
+}
+
+/// Convert a hex value followed by a checksum byte, like `0x1234:26`,
+/// to a number value or return an error.
+///
+/// The value is hex digits with an optional `0x` prefix and separators
+/// `_`, the checksum after the `:` is exactly two hex digits. The
+/// checksum is computed over the bytes of the value as written, most
+/// significant first, with a leading zero digit added to an odd number
+/// of digits, using a function like [`xor_checksum`] or
+/// [`sum_checksum`]. A mismatch is a `ChecksumMismatch` error, and a
+/// checksum that is missing or not two digits long is an
+/// `IncompleteByte` error.
+///
+/// # Arguments
+/// - `text`: Hex value and checksum, separated by `:`.
+/// - `checksum`: Function computing the checksum of the value bytes.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_checked_hex, sum_checksum, xor_checksum, ParseLiteralError};
+///
+/// assert_eq!(parse_checked_hex::<u16>("0x1234:26", xor_checksum), Ok(0x1234));
+/// assert_eq!(parse_checked_hex::<u16>("0x1234:46", sum_checksum), Ok(0x1234));
+/// assert_eq!(parse_checked_hex::<u16>("0x1234:3F", xor_checksum), Err(ParseLiteralError::ChecksumMismatch));
+/// ```
+pub fn parse_checked_hex<T: Num>(text: &str, checksum: impl Fn(&[u8]) -> u8) -> Result<T, ParseLiteralError> {
+    let text = text.trim();
+    let (value, check) = text.rsplit_once(':').unwrap_or((text, ""));
+    if check.len() != 2 {
+        return Err(ParseLiteralError::IncompleteByte);
+    }
+    if !check.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(ParseLiteralError::InvalidDigit);
+    }
+    let check = u8::from_str_radix(check, 16).map_err(|_| ParseLiteralError::InvalidDigit)?;
+    let value = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")).unwrap_or(value);
+    let digits = value.replace("_", "");
+    if digits.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(ParseLiteralError::InvalidDigit);
+    }
+    let padded = if digits.len() % 2 == 1 { format!("0{digits}") } else { digits.clone() };
+    let bytes: Vec<u8> = (0..padded.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&padded[i..i + 2], 16).unwrap())
+        .collect();
+    if checksum(&bytes) != check {
+        return Err(ParseLiteralError::ChecksumMismatch);
+    }
+    from_digits(false, &digits, 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colon_hex_big_endian_works() {
+        assert_eq!(parse_colon_hex::<u32>("DE:AD:BE:EF", Endian::Big), Ok(0xDEADBEEF));
+        assert_eq!(parse_colon_hex::<u64>("00:1a:2B:3c:4D:5e", Endian::Big), Ok(0x001A2B3C4D5E));
+        assert_eq!(parse_colon_hex::<u8>(" ff ", Endian::Big), Ok(0xFF));
+        assert_eq!(parse_colon_hex::<u16>("00:00:00:01", Endian::Big), Ok(1));
+    }
+
+    #[test]
+    fn colon_hex_little_endian_works() {
+        assert_eq!(parse_colon_hex::<u32>("DE:AD:BE:EF", Endian::Little), Ok(0xEFBEADDE));
+        assert_eq!(parse_colon_hex::<u16>("01:00", Endian::Little), Ok(1));
+        assert_eq!(parse_colon_hex::<u16>("01:00:00:00", Endian::Little), Ok(1));
+    }
+
+    #[test]
+    fn colon_hex_overflow_fails() {
+        assert_eq!(parse_colon_hex::<u16>("01:00:00", Endian::Big), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_colon_hex::<u16>("00:00:01", Endian::Little), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_colon_hex::<i8>("80", Endian::Big), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn colon_hex_malformed_fails() {
+        assert_eq!(parse_colon_hex::<u32>("DE:AD:BE:EG", Endian::Big), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_colon_hex::<u32>("+D:EF", Endian::Big), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_colon_hex::<u32>("DE::EF", Endian::Big), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_colon_hex::<u32>("", Endian::Big), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_colon_hex::<u32>("DE:A:BE", Endian::Big), Err(ParseLiteralError::IncompleteByte));
+        assert_eq!(parse_colon_hex::<u32>("DEAD:BE", Endian::Big), Err(ParseLiteralError::IncompleteByte));
+    }
+
+    #[test]
+    fn to_bytes_big_endian_works() {
+        assert_eq!(parse_to_bytes("0xCAFE", 2, Endian::Big), Ok(vec![0xCA, 0xFE]));
+        assert_eq!(parse_to_bytes("0xCAFE", 4, Endian::Big), Ok(vec![0x00, 0x00, 0xCA, 0xFE]));
+        assert_eq!(parse_to_bytes("0xDEAD_BEEF", 4, Endian::Big), Ok(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+        assert_eq!(parse_to_bytes("258", 2, Endian::Big), Ok(vec![0x01, 0x02]));
+        assert_eq!(parse_to_bytes("0", 4, Endian::Big), Ok(vec![0; 4]));
+    }
+
+    #[test]
+    fn to_bytes_little_endian_works() {
+        assert_eq!(parse_to_bytes("0xCAFE", 2, Endian::Little), Ok(vec![0xFE, 0xCA]));
+        assert_eq!(parse_to_bytes("0xCAFE", 4, Endian::Little), Ok(vec![0xFE, 0xCA, 0x00, 0x00]));
+        assert_eq!(parse_to_bytes("0xDEAD_BEEF", 4, Endian::Little), Ok(vec![0xEF, 0xBE, 0xAD, 0xDE]));
+        assert_eq!(parse_to_bytes("'A'", 2, Endian::Little), Ok(vec![0x41, 0x00]));
+    }
+
+    #[test]
+    fn to_bytes_negative_works() {
+        assert_eq!(parse_to_bytes("-1", 2, Endian::Big), Ok(vec![0xFF, 0xFF]));
+        assert_eq!(parse_to_bytes("-2", 4, Endian::Little), Ok(vec![0xFE, 0xFF, 0xFF, 0xFF]));
+        assert_eq!(parse_to_bytes("-0x8000", 2, Endian::Big), Ok(vec![0x80, 0x00]));
+        assert_eq!(parse_to_bytes("-0x8001", 2, Endian::Big), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn to_bytes_too_wide_fails() {
+        assert_eq!(parse_to_bytes("0x1_0000", 2, Endian::Big), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_to_bytes("0x1_0000_0000", 4, Endian::Little), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_to_bytes("256", 1, Endian::Big), Err(ParseLiteralError::Overflow));
+        let max = format!("{:#x}", u128::MAX);
+        assert_eq!(parse_to_bytes(&max, 16, Endian::Big), Ok(vec![0xFF; 16]));
+    }
+
+    #[test]
+    fn to_bytes_invalid_fails() {
+        assert_eq!(parse_to_bytes("1", 0, Endian::Big), Err(ParseLiteralError::InvalidWidth));
+        assert_eq!(parse_to_bytes("1", 17, Endian::Big), Err(ParseLiteralError::InvalidWidth));
+        assert_eq!(parse_to_bytes("0xFG", 2, Endian::Big), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn checked_hex_matching_works() {
+        assert_eq!(parse_checked_hex::<u16>("0x1234:26", xor_checksum), Ok(0x1234));
+        assert_eq!(parse_checked_hex::<u16>("1234:26", xor_checksum), Ok(0x1234));
+        assert_eq!(parse_checked_hex::<u32>(" 0xDEAD_BEEF:22 ", xor_checksum), Ok(0xDEAD_BEEF));
+        assert_eq!(parse_checked_hex::<u16>("0x123:22", xor_checksum), Ok(0x123));
+        assert_eq!(parse_checked_hex::<u16>("0x1234:46", sum_checksum), Ok(0x1234));
+        assert_eq!(parse_checked_hex::<u16>("0xFF02:01", sum_checksum), Ok(0xFF02));
+        assert_eq!(parse_checked_hex::<u8>("0x7F:80", |bytes| !bytes[0]), Ok(0x7F));
+    }
+
+    #[test]
+    fn checked_hex_mismatching_fails() {
+        assert_eq!(parse_checked_hex::<u16>("0x1234:3F", xor_checksum), Err(ParseLiteralError::ChecksumMismatch));
+        assert_eq!(parse_checked_hex::<u16>("0x1234:26", sum_checksum), Err(ParseLiteralError::ChecksumMismatch));
+        assert_eq!(parse_checked_hex::<u16>("0x1235:26", xor_checksum), Err(ParseLiteralError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn checked_hex_malformed_fails() {
+        assert_eq!(parse_checked_hex::<u16>("0x1234", xor_checksum), Err(ParseLiteralError::IncompleteByte));
+        assert_eq!(parse_checked_hex::<u16>("0x1234:2", xor_checksum), Err(ParseLiteralError::IncompleteByte));
+        assert_eq!(parse_checked_hex::<u16>("0x1234:+6", xor_checksum), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_checked_hex::<u16>("0x12G4:26", xor_checksum), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_checked_hex::<u16>("0x:00", xor_checksum), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_checked_hex::<u8>("0x1234:26", xor_checksum), Err(ParseLiteralError::Overflow));
+    }
+}
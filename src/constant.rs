@@ -0,0 +1,288 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::ParseLiteralError;
+use crate::format::RUST_SUFFIXES;
+use crate::scan::{const_try, read_body, read_digits, Body, Grammar};
+
+/// Convert an integer literal to the two's complement bits of a value in
+/// `min..=max` at compile time, or return an error.
+///
+/// This reads the literal with the same `const` scanner as
+/// `parse_literal`, for the `parse_literal!` macro. Unlike
+/// `parse_literal`, only ASCII whitespace is trimmed.
+pub const fn parse_int_literal(text: &str, min: i128, max: u128) -> Result<u128, ParseLiteralError> {
+    let text = text.as_bytes().trim_ascii();
+    if let [b'\'', chr, b'\''] = text {
+        return fit(false, *chr as u128, min, max);
+    }
+    let body = const_try!(read_body(text, &Grammar::DEFAULT));
+    let digits = const_try!(read_digits(text, &body, &Grammar::DEFAULT));
+    let (min, max) = match digits.suffix {
+        Some((suffix_min, suffix_max)) => {
+            (if suffix_min > min { suffix_min } else { min }, if suffix_max < max { suffix_max } else { max })
+        },
+        None => (min, max),
+    };
+    match digits.magnitude {
+        Some(magnitude) => fit(body.negative, magnitude, min, max),
+        None => Err(ParseLiteralError::Overflow),
+    }
+}
+
+/// Panic with the error message if parsing failed, which turns a failed
+/// constant evaluation into a compile error.
+pub const fn unwrap_int_literal(result: Result<u128, ParseLiteralError>) -> u128 {
+    let bits = match &result {
+        Ok(bits) => *bits,
+        Err(error) => panic!("{}", error.message()),
+    };
+    // The error type has a destructor, which constant evaluation cannot
+    // run, but there is nothing to drop for `Ok`.
+    std::mem::forget(result);
+    bits
+}
+
+/// Convert the digits of a number in a fixed radix to a value at compile
+/// time, or panic.
+///
+/// The text holds bare digits of `RADIX`, without a prefix or sign, and
+/// may contain `_` separators. Letters are digits in either case, so
+/// `"ff"` and `"FF"` are both 255 in radix 16. Only ASCII whitespace is
+/// trimmed. In a constant, malformed digits, a value that does not fit
+/// `u64` or a radix outside 2 to 36 fail the build with the error
+/// message, at runtime they panic.
+///
+/// # Arguments
+/// - `text`: Digits of a number in `RADIX`.
+/// # Returns
+/// - Numerical result.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_in_radix;
+///
+/// const MASKS: [u64; 3] = [parse_in_radix::<16>("FF"), parse_in_radix::<16>("FF00"), parse_in_radix::<2>("1010")];
+/// assert_eq!(MASKS, [0xFF, 0xFF00, 0b1010]);
+/// ```
+pub const fn parse_in_radix<const RADIX: u32>(text: &str) -> u64 {
+    if RADIX < 2 || RADIX > 36 {
+        panic!("{}", ParseLiteralError::InvalidRadix.message());
+    }
+    unwrap_int_literal(convert_in_radix(text.as_bytes().trim_ascii(), RADIX)) as u64
+}
+
+/// Convert bare digits of `radix` to a value that fits `u64`.
+const fn convert_in_radix(digits: &[u8], radix: u32) -> Result<u128, ParseLiteralError> {
+    let body = Body { negative: false, start: 0, end: digits.len() };
+    let digits = const_try!(read_digits(digits, &body, &Grammar { fixed_radix: Some(radix), ..Grammar::DEFAULT }));
+    match digits.magnitude {
+        Some(magnitude) => fit(false, magnitude, 0, u64::MAX as u128),
+        None => Err(ParseLiteralError::Overflow),
+    }
+}
+
+/// The primitive integer types `literal_consts!` can declare constants of.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a primitive integer type",
+    label = "expected a primitive integer type like `u32`"
+)]
+pub trait PrimitiveInteger {}
+
+macro_rules! impl_primitive_integer {
+    ($($t:ty)*) => {$(
+        impl PrimitiveInteger for $t {}
+    )*};
+}
+
+impl_primitive_integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Fail the build unless `T` is a primitive integer type, which the casts
+/// in `literal_consts!` would otherwise accept for floats.
+pub const fn assert_integer<T: PrimitiveInteger>() {}
+
+/// Declare constants of primitive integer types from literals, converted
+/// at compile time.
+///
+/// Each entry reads like a `const` item with a string literal as value,
+/// and may have attributes, doc comments and a visibility. The literals
+/// follow the rules of `parse_literal`. An invalid literal or a value out
+/// of range for the type fails the build with the error message.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::literal_consts;
+///
+/// literal_consts! {
+///     /// The high byte of a word.
+///     pub const MASK: u32 = "0xFF00";
+///     const MODE: u16 = "0o755";
+///     const MIN: i8 = "-0x80";
+/// }
+///
+/// assert_eq!([MASK, MODE as u32], [0xFF00, 0o755]);
+/// assert_eq!(MIN, i8::MIN);
+/// ```
+#[macro_export]
+macro_rules! literal_consts {
+    ($($(#[$attr:meta])* $vis:vis const $name:ident: $ty:ty = $text:literal;)*) => {$(
+        $(#[$attr])*
+        $vis const $name: $ty = {
+            $crate::__private::assert_integer::<$ty>();
+            $crate::__private::unwrap_int_literal(
+                $crate::__private::parse_int_literal($text, <$ty>::MIN as i128, <$ty>::MAX as u128)
+            ) as $ty
+        };
+    )*};
+}
+
+/// Split a Rust integer type suffix like `u8` off the end of an unsigned
+/// literal and return the range of the type, unless nothing precedes it.
+/// Base 36 digits include the suffix letters, so nothing is split off
+/// after a `0z` prefix.
+pub(crate) const fn split_rust_suffix(body: &[u8]) -> Option<(&[u8], i128, u128)> {
+    if let [b'0', b'z' | b'Z', ..] = body {
+        return None;
+    }
+    let mut i = 0;
+    while i < RUST_SUFFIXES.len() {
+        let (suffix, min, max) = RUST_SUFFIXES[i];
+        i += 1;
+        let suffix = suffix.as_bytes();
+        if body.len() <= suffix.len() {
+            continue;
+        }
+        let (digits, end) = body.split_at(body.len() - suffix.len());
+        let mut j = 0;
+        while j < suffix.len() && end[j] == suffix[j] {
+            j += 1;
+        }
+        if j == suffix.len() {
+            return Some((digits, min, max));
+        }
+    }
+    None
+}
+
+/// Split a Rust integer type suffix off an unsigned literal like
+/// `split_rust_suffix`, and return the rest with the range of the type,
+/// if there was a suffix.
+pub(crate) fn strip_rust_suffix(text: &str) -> (&str, Option<(i128, u128)>) {
+    match split_rust_suffix(text.as_bytes()) {
+        Some((body, min, max)) => (&text[..body.len()], Some((min, max))),
+        None => (text, None),
+    }
+}
+
+/// Check that a signed magnitude lies in `min..=max` and return its two's
+/// complement bits.
+pub(crate) const fn fit(negative: bool, magnitude: u128, min: i128, max: u128) -> Result<u128, ParseLiteralError> {
+    if negative && min == 0 {
+        Err(ParseLiteralError::InvalidSign)
+    } else if negative && magnitude > min.unsigned_abs() {
+        Err(ParseLiteralError::Overflow)
+    } else if negative {
+        Ok(magnitude.wrapping_neg())
+    } else if magnitude > max {
+        Err(ParseLiteralError::Overflow)
+    } else {
+        Ok(magnitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NumLiteralTrait;
+
+    const TEXTS: [&str; 44] = [
+        "0", "00", "123", " 42 ", "+7", "-7", "0xCAFE", "0XcafE", "0b1010", "0B_1_0", "0o777", "0755", "0zZ9",
+        "'A'", "1_000", "0x", "", "-", "+-1", "- 1", "0x-1", "CAFE", "08", "0b2", "0x1FF", "-0x80", "-0x81",
+        "-0", "__", "0x_", "0hFF", "0H", "0xFFu8", "0x100u8", "-1u8", "-0x80i8", "-0x81i8", "0u8", "017u16",
+        "0b1i128", "u8", "0xu8", "0zu8", "1_000u32",
+    ];
+
+    /// Compare the constant parser with `parse_literal` for a type.
+    fn assert_same<T: NumLiteralTrait<T> + std::fmt::Debug>(min: i128, max: u128, wrap: fn(u128) -> T) {
+        for text in TEXTS {
+            let result = parse_int_literal(text, min, max).map(wrap);
+            assert_eq!(result, T::parse_literal(text), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn const_matches_parse_literal() {
+        assert_same::<u8>(u8::MIN as i128, u8::MAX as u128, |bits| bits as u8);
+        assert_same::<i8>(i8::MIN as i128, i8::MAX as u128, |bits| bits as i8);
+        assert_same::<u32>(u32::MIN as i128, u32::MAX as u128, |bits| bits as u32);
+        assert_same::<i64>(i64::MIN as i128, i64::MAX as u128, |bits| bits as i64);
+    }
+
+    #[test]
+    fn const_limits_work() {
+        let max = format!("{}", u128::MAX);
+        assert_eq!(parse_int_literal(&max, 0, u128::MAX), Ok(u128::MAX));
+        assert_eq!(parse_int_literal(&format!("{max}0"), 0, u128::MAX), Err(ParseLiteralError::Overflow));
+        let min = format!("{}", i128::MIN);
+        let result = parse_int_literal(&min, i128::MIN, i128::MAX as u128);
+        assert_eq!(result.map(|bits| bits as i128), Ok(i128::MIN));
+    }
+
+    #[test]
+    fn const_radix_table_works() {
+        const TABLE: [u64; 5] = [
+            parse_in_radix::<16>("0"),
+            parse_in_radix::<16>("cafe"),
+            parse_in_radix::<16>("DEAD_BEEF"),
+            parse_in_radix::<16>(" 7F "),
+            parse_in_radix::<16>("FFFFFFFFFFFFFFFF"),
+        ];
+        assert_eq!(TABLE, [0, 0xCAFE, 0xDEAD_BEEF, 0x7F, u64::MAX]);
+        assert_eq!(parse_in_radix::<36>("Z"), 35);
+        assert_eq!(parse_in_radix::<7>("123"), 66);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid digit")]
+    fn const_radix_invalid_digit_panics() {
+        parse_in_radix::<8>("18");
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn const_radix_overflow_panics() {
+        parse_in_radix::<16>("1_0000_0000_0000_0000");
+    }
+
+    #[test]
+    #[should_panic(expected = "radix")]
+    fn const_radix_out_of_range_panics() {
+        parse_in_radix::<37>("1");
+    }
+
+    #[test]
+    fn literal_consts_work() {
+        literal_consts! {
+            const MASK: u32 = "0xFF00";
+            const BITS: u8 = "0b1010_0101";
+            #[allow(dead_code)]
+            pub(crate) const MIN: i64 = "-0x8000_0000_0000_0000";
+            const CHAR: u8 = "'A'";
+        }
+        assert_eq!([MASK, BITS as u32, CHAR as u32], [0xFF00, 0b1010_0101, 65]);
+        assert_eq!(MIN, i64::MIN);
+    }
+
+    #[test]
+    fn const_evaluation_works() {
+        const VALUE: u32 = unwrap_int_literal(parse_int_literal("0xCAFE", 0, u32::MAX as u128)) as u32;
+        assert_eq!(VALUE, 0xCAFE);
+    }
+}
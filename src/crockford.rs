@@ -0,0 +1,140 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::ParseLiteralError;
+use crate::parse_literal::from_digits;
+
+/// Symbols for the values 0 to 36, where 32 and up only appear as check
+/// symbols.
+const SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+/// Value of a Crockford Base32 symbol, with the ambiguous letters `I`,
+/// `L` and `O` read as the digits they resemble.
+fn symbol_value(c: char) -> Option<u32> {
+    let c = match c.to_ascii_uppercase() {
+        'I' | 'L' => '1',
+        'O' => '0',
+        c => c,
+    };
+    SYMBOLS.iter().position(|&s| char::from(s) == c).map(|value| value as u32)
+}
+
+/// Translate the Crockford symbols into the standard base-32 digits of
+/// `from_str_radix`, skipping the `-` separators.
+fn decode(text: &str) -> Result<String, ParseLiteralError> {
+    text.chars()
+        .filter(|&c| c != '-')
+        .map(|c| match symbol_value(c) {
+            Some(value) if value < 32 => Ok(char::from_digit(value, 32).unwrap()),
+            _ => Err(ParseLiteralError::InvalidDigit),
+        })
+        .collect()
+}
+
+/// Convert a Crockford Base32 text to a number value or return an error.
+///
+/// Decoding is case-insensitive, reads `I` and `L` as `1` and `O` as `0`,
+/// and ignores `-` separators, as the specification demands.
+///
+/// # Arguments
+/// - `text`: Crockford Base32 encoded number.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_crockford_base32;
+///
+/// assert_eq!(parse_crockford_base32::<u32>("16J"), Ok(1234));
+/// assert_eq!(parse_crockford_base32::<u32>("1-6j"), Ok(1234));
+/// assert_eq!(parse_crockford_base32::<u32>("Lo"), Ok(32));
+/// ```
+pub fn parse_crockford_base32<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    from_digits(false, &decode(text.trim())?, 32)
+}
+
+/// Convert a Crockford Base32 text ending in a check symbol to a number
+/// value or return an error.
+///
+/// The check symbol is the value modulo 37, using the extra symbols
+/// `*`, `~`, `$`, `=` and `U` for 32 to 36. A mismatch is a
+/// `ChecksumMismatch` error.
+///
+/// # Arguments
+/// - `text`: Crockford Base32 encoded number with a check symbol.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_crockford_base32_checked, ParseLiteralError};
+///
+/// assert_eq!(parse_crockford_base32_checked::<u32>("16JD"), Ok(1234));
+/// assert_eq!(parse_crockford_base32_checked::<u32>("16JE"), Err(ParseLiteralError::ChecksumMismatch));
+/// ```
+pub fn parse_crockford_base32_checked<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let text = text.trim();
+    let Some(check) = text.chars().next_back() else {
+        return Err(ParseLiteralError::Empty);
+    };
+    let digits = decode(&text[..text.len() - check.len_utf8()])?;
+    if digits.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    let check = symbol_value(check).ok_or(ParseLiteralError::InvalidDigit)?;
+    let remainder = digits.chars()
+        .map(|c| c.to_digit(32).unwrap())
+        .fold(0, |remainder, digit| (remainder * 32 + digit) % 37);
+    if remainder != check {
+        return Err(ParseLiteralError::ChecksumMismatch);
+    }
+    from_digits(false, &digits, 32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crockford_works() {
+        assert_eq!(parse_crockford_base32::<u32>("0"), Ok(0));
+        assert_eq!(parse_crockford_base32::<u32>("Z"), Ok(31));
+        assert_eq!(parse_crockford_base32::<u64>("7ZZ-ZZZZ-ZZZ"), Ok(0xFFFF_FFFF_FFFF));
+    }
+
+    #[test]
+    fn crockford_ambiguous_letters_work() {
+        assert_eq!(parse_crockford_base32::<u32>("il0o"), parse_crockford_base32("1100"));
+    }
+
+    #[test]
+    fn crockford_invalid_fails() {
+        assert_eq!(parse_crockford_base32::<u32>("U"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_crockford_base32::<u32>("*"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_crockford_base32::<u32>(""), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_crockford_base32::<u8>("80"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn crockford_check_symbol_works() {
+        assert_eq!(parse_crockford_base32_checked::<u32>("16jd"), Ok(1234));
+        assert_eq!(parse_crockford_base32_checked::<u32>("10*"), Ok(32));
+        assert_eq!(parse_crockford_base32_checked::<u32>("14U"), Ok(36));
+        assert_eq!(parse_crockford_base32_checked::<u32>("00"), Ok(0));
+    }
+
+    #[test]
+    fn crockford_check_symbol_fails() {
+        assert_eq!(parse_crockford_base32_checked::<u32>("16J"), Err(ParseLiteralError::ChecksumMismatch));
+        assert_eq!(parse_crockford_base32_checked::<u32>("16J#"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_crockford_base32_checked::<u32>("U"), Err(ParseLiteralError::Empty));
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::LiteralFormat;
+
+/// A parsed number together with how its literal was written, returned by
+/// `parse_literal_details`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LiteralDetails<'a, T> {
+    /// The parsed number.
+    pub value: T,
+    /// The format the literal was written in.
+    pub format: LiteralFormat,
+    /// The radix prefix exactly as written, like `"0X"` or `"0"` for
+    /// octal, or `""` for decimal and char literals.
+    pub prefix: &'a str,
+    /// The sign as written, so `"+5"` and `"5"` can be told apart.
+    pub sign: LiteralSign,
+}
+
+/// The sign of a literal as written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LiteralSign {
+    /// No sign was written, like `"5"`.
+    Implicit,
+    /// An explicit `+` was written, like `"+5"`.
+    ExplicitPlus,
+    /// A `-` was written, like `"-5"`.
+    Minus,
+}
+
+impl<'a, T> LiteralDetails<'a, T> {
+    pub(crate) fn new(value: T, format: LiteralFormat, prefix: &'a str, sign: LiteralSign) -> Self {
+        Self { value, format, prefix, sign }
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::time::Duration;
+use num_traits::Num;
+use crate::{NumLiteralTrait, ParseLiteralError};
+use crate::parse_literal::from_digits;
+
+/// The duration of `count` times a time unit.
+fn unit_duration(count: u64, unit: &str) -> Result<Duration, ParseLiteralError> {
+    let seconds = |factor: u64| count.checked_mul(factor).map(Duration::from_secs).ok_or(ParseLiteralError::Overflow);
+    match unit {
+        "ns" => Ok(Duration::from_nanos(count)),
+        "us" | "µs" => Ok(Duration::from_micros(count)),
+        "ms" => Ok(Duration::from_millis(count)),
+        "s" => seconds(1),
+        "m" => seconds(60),
+        "h" => seconds(3600),
+        _ => Err(ParseLiteralError::InvalidUnit),
+    }
+}
+
+/// Convert a duration with time units, like `500ms` or `1m30s`, to a
+/// `std::time::Duration` or return an error.
+///
+/// Each part is a whole decimal number followed by one of the units
+/// `ns`, `us` (or `µs`), `ms`, `s`, `m` and `h`. Parts may follow each
+/// other in any order and are added up, so `1m30s` is 90 seconds. A
+/// missing or unknown unit is an `InvalidUnit` error, and a total beyond
+/// the range of `Duration` is an `Overflow` error.
+///
+/// # Arguments
+/// - `text`: Textual representation of a duration.
+/// # Returns
+/// - Duration or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use num_literal_traits::parse_duration_literal;
+///
+/// assert_eq!(parse_duration_literal("500ms"), Ok(Duration::from_millis(500)));
+/// assert_eq!(parse_duration_literal("1m30s"), Ok(Duration::from_secs(90)));
+/// ```
+pub fn parse_duration_literal(text: &str) -> Result<Duration, ParseLiteralError> {
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    let mut total = Duration::ZERO;
+    while !rest.is_empty() {
+        if rest.starts_with(['+', '-']) {
+            return Err(ParseLiteralError::InvalidSign);
+        }
+        let count_len = rest.find(|c: char| c != '_' && !c.is_ascii_digit()).unwrap_or(rest.len());
+        let count = from_digits(false, &rest[..count_len].replace("_", ""), 10)?;
+        let unit = rest[count_len..].trim_start();
+        let unit_len = unit.find(|c: char| !c.is_alphabetic()).unwrap_or(unit.len());
+        let part = unit_duration(count, &unit[..unit_len])?;
+        total = total.checked_add(part).ok_or(ParseLiteralError::Overflow)?;
+        rest = unit[unit_len..].trim_start();
+    }
+    Ok(total)
+}
+
+/// Split the numbers and designators off one part of an ISO 8601
+/// duration, where the designators must follow the order of `units`.
+fn iso8601_part<T: Num>(mut text: &str, mut units: &str) -> Result<Vec<(T, char)>, ParseLiteralError> {
+    let mut components = Vec::new();
+    while !text.is_empty() {
+        if text.starts_with(['+', '-']) {
+            return Err(ParseLiteralError::InvalidSign);
+        }
+        let number_len = text.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',').unwrap_or(text.len());
+        let (number, rest) = text.split_at(number_len);
+        let unit = rest.chars().next().ok_or(ParseLiteralError::InvalidUnit)?;
+        let position = units.find(unit).ok_or(ParseLiteralError::InvalidUnit)?;
+        units = &units[position + 1..];
+        if number.is_empty() {
+            return Err(ParseLiteralError::Empty);
+        }
+        components.push((T::parse_float_literal(number.replace(',', "."))?, unit));
+        text = &rest[1..];
+    }
+    Ok(components)
+}
+
+/// Convert the numbers of an ISO 8601 duration, like `PT1H30M`, to number
+/// values with their unit designators or return an error.
+///
+/// The duration starts with `P`, followed by the date part with the
+/// designators `Y`, `M`, `W` and `D`, and after a `T` the time part with
+/// `H`, `M` and `S`, each in this order and at most once. Numbers are
+/// decimal and may have a fraction, written with `.` or `,`, which is an
+/// error for integer types. The designator `M` stands for months in the
+/// date part and minutes in the time part. A text without `P` is an
+/// `UnsupportedFormat` error, a missing, unknown or misplaced designator
+/// an `InvalidUnit` error. The components are not added up, see
+/// `parse_duration_literal` for durations as a whole.
+///
+/// # Arguments
+/// - `text`: ISO 8601 duration.
+/// # Returns
+/// - Numbers with their designators or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::iso8601_numeric_components;
+///
+/// assert_eq!(iso8601_numeric_components::<u32>("PT1H30M"), Ok(vec![(1, 'H'), (30, 'M')]));
+/// assert_eq!(iso8601_numeric_components::<f64>("P1DT0.5S"), Ok(vec![(1.0, 'D'), (0.5, 'S')]));
+/// ```
+pub fn iso8601_numeric_components<T: Num>(text: &str) -> Result<Vec<(T, char)>, ParseLiteralError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    let duration = text.strip_prefix('P').ok_or(ParseLiteralError::UnsupportedFormat)?;
+    let (date, time) = match duration.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (duration, None),
+    };
+    let mut components = iso8601_part(date, "YMWD")?;
+    match time {
+        Some("") => return Err(ParseLiteralError::Empty),
+        Some(time) => components.extend(iso8601_part(time, "HMS")?),
+        None => {},
+    }
+    if components.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    Ok(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_units_work() {
+        assert_eq!(parse_duration_literal("1500ns"), Ok(Duration::from_nanos(1500)));
+        assert_eq!(parse_duration_literal("1500us"), Ok(Duration::from_micros(1500)));
+        assert_eq!(parse_duration_literal("1500µs"), Ok(Duration::from_micros(1500)));
+        assert_eq!(parse_duration_literal("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration_literal("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration_literal("5m"), Ok(Duration::from_secs(300)));
+        assert_eq!(parse_duration_literal("1h"), Ok(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn duration_literal_forms_work() {
+        assert_eq!(parse_duration_literal(" 250 ms "), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration_literal("0h30m"), Ok(Duration::from_secs(1800)));
+        assert_eq!(parse_duration_literal("1_000ms"), Ok(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn duration_compound_works() {
+        assert_eq!(parse_duration_literal("1m30s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration_literal("1h 2m 3s 4ms"), Ok(Duration::new(3723, 4_000_000)));
+        assert_eq!(parse_duration_literal("30s1m"), Ok(Duration::from_secs(90)));
+    }
+
+    #[test]
+    fn duration_invalid_fails() {
+        assert_eq!(parse_duration_literal(""), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_duration_literal("500"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(parse_duration_literal("5d"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(parse_duration_literal("1m30"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(parse_duration_literal("-5s"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(parse_duration_literal("ms"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_duration_literal("0x10s"), Err(ParseLiteralError::InvalidUnit));
+    }
+
+    #[test]
+    fn iso8601_components_work() {
+        assert_eq!(iso8601_numeric_components::<u32>("PT1H30M45S"), Ok(vec![(1, 'H'), (30, 'M'), (45, 'S')]));
+        let components = vec![(1, 'Y'), (2, 'M'), (10, 'D'), (2, 'H'), (30, 'M')];
+        assert_eq!(iso8601_numeric_components::<u32>(" P1Y2M10DT2H30M "), Ok(components));
+        assert_eq!(iso8601_numeric_components::<u32>("P3W"), Ok(vec![(3, 'W')]));
+        assert_eq!(iso8601_numeric_components::<u32>("PT010M"), Ok(vec![(10, 'M')]));
+        assert_eq!(iso8601_numeric_components::<f64>("PT1.5S"), Ok(vec![(1.5, 'S')]));
+        assert_eq!(iso8601_numeric_components::<f64>("PT0,25H"), Ok(vec![(0.25, 'H')]));
+    }
+
+    #[test]
+    fn iso8601_components_malformed_fail() {
+        assert_eq!(iso8601_numeric_components::<u32>(""), Err(ParseLiteralError::Empty));
+        assert_eq!(iso8601_numeric_components::<u32>("P"), Err(ParseLiteralError::Empty));
+        assert_eq!(iso8601_numeric_components::<u32>("P1DT"), Err(ParseLiteralError::Empty));
+        assert_eq!(iso8601_numeric_components::<u32>("PTH"), Err(ParseLiteralError::Empty));
+        assert_eq!(iso8601_numeric_components::<u32>("1H30M"), Err(ParseLiteralError::UnsupportedFormat));
+        assert_eq!(iso8601_numeric_components::<u32>("PT1H30"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(iso8601_numeric_components::<u32>("P1H"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(iso8601_numeric_components::<u32>("PT1S1H"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(iso8601_numeric_components::<u32>("PT1H1H"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(iso8601_numeric_components::<u32>("PT1HT1M"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(iso8601_numeric_components::<u32>("PT1h"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(iso8601_numeric_components::<i32>("PT-1H"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(iso8601_numeric_components::<u32>("PT1.5S"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(iso8601_numeric_components::<u8>("PT300S"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn duration_overflow_fails() {
+        assert_eq!(parse_duration_literal(&format!("{}h", u64::MAX / 3600 + 1)), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_duration_literal(&format!("{}s1s", u64::MAX)), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_duration_literal(&format!("{}0s", u64::MAX)), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_duration_literal(&format!("{}s", u64::MAX)), Ok(Duration::from_secs(u64::MAX)));
+    }
+}
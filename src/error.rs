@@ -0,0 +1,202 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+/// The reasons a text can fail to parse as a numeric literal.
+///
+/// The error is `Send + Sync + 'static` and implements
+/// `std::error::Error`, so `?` converts it into a boxed error, or into
+/// the error types of crates like `anyhow`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::error::Error;
+/// use num_literal_traits::NumLiteralTrait;
+///
+/// fn port(text: &str) -> Result<u16, Box<dyn Error + Send + Sync>> {
+///     Ok(u16::parse_literal(text)?)
+/// }
+///
+/// assert_eq!(port("0x50").unwrap(), 80);
+/// assert_eq!(port("0x1_0000").unwrap_err().to_string(), "literal is out of range for the target type");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseLiteralError {
+    /// There are no digits to convert, as in `""` or `"0x"`.
+    Empty,
+    /// A character is not a valid digit for the detected radix.
+    InvalidDigit,
+    /// The digits are valid, but the value does not fit the target type.
+    Overflow,
+    /// Text follows the literal, starting at this byte offset into the
+    /// input.
+    TrailingCharacters {
+        /// Byte offset of the first trailing character.
+        offset: usize,
+    },
+    /// A `+` or `-` without a number after it.
+    LoneSign,
+    /// A sign that is doubled, misplaced, not directly followed by the
+    /// number, or a `-` for a type without negative values.
+    InvalidSign,
+    /// A digit separator `_` directly follows the radix prefix, as in
+    /// `0x_FF`, which strict parsing rejects.
+    SeparatorAfterPrefix,
+    /// A digit separator `_` is not between digits, as in `_1`, `1_` or
+    /// `1__0`.
+    MisplacedSeparator,
+    /// A decimal number starts with a zero, which is rejected where a
+    /// leading zero does not select octal.
+    LeadingZero,
+    /// The number has a fractional part, but the conversion needs a
+    /// whole number.
+    NotIntegral,
+    /// A type suffix is not valid, like the doubled `U` in `123UU`.
+    InvalidSuffix,
+    /// An explicitly given radix is outside the supported range of 2 to
+    /// 36.
+    InvalidRadix,
+    /// A check symbol or checksum does not match the value.
+    ChecksumMismatch,
+    /// The digits do not divide into whole bytes, as needed to reverse
+    /// their byte order.
+    IncompleteByte,
+    /// The literal format is valid, but not supported by this
+    /// conversion.
+    UnsupportedFormat,
+    /// Whitespace precedes the literal where it is not trimmed.
+    LeadingWhitespace,
+    /// Whitespace follows the literal where it is not trimmed.
+    TrailingWhitespace,
+    /// A key-value pair lacks its separator or key, or has more than one
+    /// separator.
+    InvalidKeyValue,
+    /// The literal, as written, is a value that does not fit the target
+    /// type. Reported instead of `Overflow` where requested.
+    OutOfRange {
+        /// The literal as written, without surrounding whitespace.
+        value: String,
+    },
+    /// The unit after a number is missing or not known, like the `d` in
+    /// the duration `5d`.
+    InvalidUnit,
+    /// The text is longer than the configured maximum length.
+    TooLong,
+    /// An expression divides by zero.
+    DivisionByZero,
+    /// A range lacks the `..` between its bounds.
+    InvalidRange,
+    /// The start of a range is greater than its end.
+    InvertedRange,
+    /// A bit-width annotation like `u12:` is malformed or out of range,
+    /// or a bit pattern does not have the digits of its exact width.
+    InvalidWidth,
+    /// Digit separators do not split the digits into groups of the
+    /// required size.
+    InvalidGrouping,
+    /// The exponent of a literal lacks its digits or has invalid ones.
+    InvalidExponent,
+    /// A list has a different number of elements than required.
+    WrongCount {
+        /// The required number of elements.
+        expected: usize,
+        /// The number of elements in the text.
+        found: usize,
+    },
+    /// A list has no field at the requested index.
+    MissingField,
+    /// A Roman numeral or number words are not in standard form.
+    InvalidNumeral,
+    /// The format of the literal is not one of the allowed formats.
+    FormatNotAllowed,
+    /// The value is zero, but the target type cannot hold zero.
+    Zero,
+    /// A number has no sign, but an explicit sign is required.
+    MissingSign,
+    /// A word in a number written in words is not a number word.
+    UnknownWord {
+        /// The unknown word.
+        word: String,
+    },
+}
+
+impl ParseLiteralError {
+    /// The error message without details like the offset, usable in
+    /// constant evaluation.
+    pub(crate) const fn message(&self) -> &'static str {
+        match self {
+            Self::Empty => "cannot parse literal from empty string",
+            Self::InvalidDigit => "invalid digit found in literal",
+            Self::Overflow => "literal is out of range for the target type",
+            Self::TrailingCharacters { .. } => "unexpected characters after literal",
+            Self::LoneSign => "sign without a number",
+            Self::InvalidSign => "invalid sign in literal",
+            Self::SeparatorAfterPrefix => "digit separator directly after radix prefix",
+            Self::MisplacedSeparator => "digit separator not between digits",
+            Self::LeadingZero => "leading zero in decimal literal",
+            Self::NotIntegral => "literal is not a whole number",
+            Self::InvalidSuffix => "invalid suffix in literal",
+            Self::InvalidRadix => "radix is outside the range 2 to 36",
+            Self::ChecksumMismatch => "checksum does not match literal",
+            Self::IncompleteByte => "literal digits do not form whole bytes",
+            Self::UnsupportedFormat => "literal format is not supported here",
+            Self::LeadingWhitespace => "whitespace before literal",
+            Self::TrailingWhitespace => "whitespace after literal",
+            Self::InvalidKeyValue => "malformed key-value pair",
+            Self::OutOfRange { .. } => "literal is out of range for the target type",
+            Self::InvalidUnit => "missing or unknown unit after number",
+            Self::TooLong => "literal exceeds the maximum length",
+            Self::DivisionByZero => "division by zero in expression",
+            Self::InvalidRange => "malformed range",
+            Self::InvertedRange => "range start is greater than its end",
+            Self::InvalidWidth => "invalid bit width",
+            Self::InvalidGrouping => "digit groups do not have the required size",
+            Self::InvalidExponent => "malformed exponent",
+            Self::WrongCount { .. } => "wrong number of list elements",
+            Self::MissingField => "list has no field at the index",
+            Self::InvalidNumeral => "numeral is not in standard form",
+            Self::FormatNotAllowed => "literal format is not allowed",
+            Self::Zero => "number would be zero for non-zero type",
+            Self::MissingSign => "literal has no sign",
+            Self::UnknownWord { .. } => "unknown number word",
+        }
+    }
+}
+
+impl fmt::Display for ParseLiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TrailingCharacters { offset } => write!(f, "{} at offset {offset}", self.message()),
+            Self::OutOfRange { value } => write!(f, "literal `{value}` is out of range for the target type"),
+            Self::UnknownWord { word } => write!(f, "{} `{word}`", self.message()),
+            Self::WrongCount { expected, found } => write!(f, "{} (expected {expected}, found {found})", self.message()),
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
+impl std::error::Error for ParseLiteralError {}
+
+/// Keep the error usable across threads: a variant holding something
+/// like an `Rc` fails to compile here.
+const _: () = {
+    const fn assert_thread_safe<T: std::error::Error + Send + Sync + 'static>() {}
+    assert_thread_safe::<ParseLiteralError>();
+};
+
+/// Report a parse failure as an `InvalidData` I/O error, keeping the
+/// message, so `?` works in functions returning `io::Result`.
+#[cfg(feature = "std")]
+impl From<ParseLiteralError> for std::io::Error {
+    fn from(error: ParseLiteralError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
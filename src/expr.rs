@@ -0,0 +1,284 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{checked_pow, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Num};
+use crate::{NumLiteralTrait, ParseLiteralError};
+use crate::format::match_prefix;
+use crate::parse_literal::{literal_len, split_sign};
+
+/// Convert the literal at the start of `rest`, with an optional `-`, and
+/// advance `rest` past it.
+fn operand<T: Num>(rest: &mut &str) -> Result<T, ParseLiteralError> {
+    let sign_len = usize::from(rest.starts_with('-'));
+    let len = match literal_len(&rest[sign_len..]) {
+        0 if rest[sign_len..].is_empty() => return Err(ParseLiteralError::Empty),
+        0 => return Err(ParseLiteralError::InvalidDigit),
+        len => sign_len + len,
+    };
+    let value = T::parse_literal(&rest[..len])?;
+    *rest = rest[len..].trim_start();
+    Ok(value)
+}
+
+/// Add or subtract a finished term from the sum of the terms before it.
+fn combine<T: CheckedAdd + CheckedSub>(sum: Option<T>, add: bool, term: T) -> Result<T, ParseLiteralError> {
+    match sum {
+        None => Some(term),
+        Some(sum) if add => sum.checked_add(&term),
+        Some(sum) => sum.checked_sub(&term),
+    }
+    .ok_or(ParseLiteralError::Overflow)
+}
+
+/// Evaluate a flat arithmetic expression over literals, like
+/// `0x10 + 0b100` or `1024*4`, or return an error.
+///
+/// The operators `+`, `-`, `*` and `/` are supported, with `*` and `/`
+/// taking precedence, and otherwise evaluated from left to right.
+/// Parentheses are not. Every operand is a literal as in `parse_literal`,
+/// optionally negated with `-`. All steps use checked arithmetic, so an
+/// intermediate result out of range, like in `1 - 2 + 3` for unsigned
+/// types, is an `Overflow` error. Division truncates, and dividing by
+/// zero is a `DivisionByZero` error.
+///
+/// # Arguments
+/// - `text`: Arithmetic expression over literals.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{eval_literal_expr, ParseLiteralError};
+///
+/// assert_eq!(eval_literal_expr::<u32>("0x10 + 0b100"), Ok(20));
+/// assert_eq!(eval_literal_expr::<u32>("2+3*4"), Ok(14));
+/// assert_eq!(eval_literal_expr::<u32>("1/0"), Err(ParseLiteralError::DivisionByZero));
+/// ```
+pub fn eval_literal_expr<T>(text: &str) -> Result<T, ParseLiteralError>
+where
+    T: Num + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv,
+{
+    let mut rest = text.trim_start();
+    let mut sum = None;
+    let mut add = true;
+    let mut term = operand::<T>(&mut rest)?;
+    while let Some(op) = rest.chars().next() {
+        if !matches!(op, '+' | '-' | '*' | '/') {
+            return Err(ParseLiteralError::TrailingCharacters { offset: text.len() - rest.len() });
+        }
+        rest = rest[1..].trim_start();
+        let value = operand::<T>(&mut rest)?;
+        term = match op {
+            '*' => term.checked_mul(&value).ok_or(ParseLiteralError::Overflow)?,
+            '/' if value.is_zero() => return Err(ParseLiteralError::DivisionByZero),
+            '/' => term.checked_div(&value).ok_or(ParseLiteralError::Overflow)?,
+            _ => {
+                sum = Some(combine(sum, add, term)?);
+                add = op == '+';
+                value
+            },
+        };
+    }
+    combine(sum, add, term)
+}
+
+/// Convert a power like `2^10` or `16^2` to a number value or return an
+/// error.
+///
+/// The base and the exponent are literals as in `parse_literal`, so
+/// `0x10^0b10` is 256 too. A sign belongs to the base, so `-2^2` is 4.
+/// The power is computed with checked arithmetic, and a result that does
+/// not fit `T` is an `Overflow` error. A negative exponent is a
+/// `NotIntegral` error, as integers cannot hold the reciprocal. Without
+/// a `^`, the text is parsed like `parse_literal`.
+///
+/// # Arguments
+/// - `text`: Base and exponent literals, split by `^`.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_power_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_power_literal::<u32>("2^10"), Ok(1024));
+/// assert_eq!(parse_power_literal::<u32>("16^2"), Ok(256));
+/// assert_eq!(parse_power_literal::<u8>("2^8"), Err(ParseLiteralError::Overflow));
+/// ```
+pub fn parse_power_literal<T: Num + CheckedMul + Clone>(text: &str) -> Result<T, ParseLiteralError> {
+    let Some((base, exponent)) = text.split_once('^') else {
+        return T::parse_literal(text);
+    };
+    let base = T::parse_literal(base)?;
+    let exponent = i128::parse_literal(exponent)?;
+    if exponent < 0 {
+        return Err(ParseLiteralError::NotIntegral);
+    }
+    usize::try_from(exponent).ok()
+        .and_then(|exponent| checked_pow(base, exponent))
+        .ok_or(ParseLiteralError::Overflow)
+}
+
+/// Convert a number in spreadsheet-style scientific notation, like
+/// `1.5×10^3`, to a number value or return an error.
+///
+/// A `×`, `x`, `X` or `*` followed by `10^` marks the exponent, with
+/// optional spaces around the multiplication sign, so `1.5×10^3`,
+/// `1.5x10^3` and `1.5 * 10^-2` are read like `1.5e3` and `1.5e-2` by
+/// `parse_float_literal`. Only a tenfold power is an exponent here,
+/// unlike in `parse_power_literal`, so `2×3^2` is an `InvalidDigit`
+/// error, and the mantissa is decimal, so a radix prefix like in
+/// `0x10^3` is an `UnsupportedFormat` error. An exponent without digits
+/// is an `InvalidExponent` error. Without the notation, the text is
+/// parsed like `parse_float_literal`.
+///
+/// # Arguments
+/// - `text`: Mantissa, multiplication sign and power of ten.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_times_ten_literal;
+///
+/// assert_eq!(parse_times_ten_literal::<f64>("1.5×10^3"), Ok(1500.0));
+/// assert_eq!(parse_times_ten_literal::<f64>("1.5x10^-3"), Ok(0.0015));
+/// assert_eq!(parse_times_ten_literal::<u32>("2 * 10^3"), Ok(2000));
+/// ```
+pub fn parse_times_ten_literal<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let text = text.trim();
+    if match_prefix(text.trim_start_matches(['+', '-'])).is_some() {
+        return Err(ParseLiteralError::UnsupportedFormat);
+    }
+    let Some(index) = text.find(['×', 'x', 'X', '*']) else {
+        return T::parse_float_literal(text);
+    };
+    let (mantissa, rest) = text.split_at(index);
+    let rest = rest[rest.chars().next().map_or(0, char::len_utf8)..].trim_start();
+    let exponent = rest.strip_prefix("10^").ok_or(ParseLiteralError::InvalidDigit)?.trim();
+    let mantissa = mantissa.trim_end();
+    if mantissa.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    if mantissa.contains(['e', 'E']) {
+        return Err(ParseLiteralError::InvalidDigit);
+    }
+    let digits = split_sign(exponent).map_err(|_| ParseLiteralError::InvalidExponent)?.1;
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ParseLiteralError::InvalidExponent);
+    }
+    T::parse_float_literal(format!("{mantissa}e{exponent}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expr_operators_work() {
+        assert_eq!(eval_literal_expr::<u32>("0x10 + 0b100"), Ok(20));
+        assert_eq!(eval_literal_expr::<u32>("1024*4"), Ok(4096));
+        assert_eq!(eval_literal_expr::<u32>("0o17 - 5"), Ok(10));
+        assert_eq!(eval_literal_expr::<u32>("'A' / 5"), Ok(13));
+        assert_eq!(eval_literal_expr::<u32>(" 42 "), Ok(42));
+        assert_eq!(eval_literal_expr::<u32>("0xFFu8 + 1"), Ok(256));
+        assert_eq!(eval_literal_expr::<i32>("-1i8*2u16"), Ok(-2));
+    }
+
+    #[test]
+    fn expr_precedence_works() {
+        assert_eq!(eval_literal_expr::<u32>("2+3*4"), Ok(14));
+        assert_eq!(eval_literal_expr::<u32>("2*3+4"), Ok(10));
+        assert_eq!(eval_literal_expr::<i32>("10-2-3"), Ok(5));
+        assert_eq!(eval_literal_expr::<i32>("10/3*3"), Ok(9));
+        assert_eq!(eval_literal_expr::<i32>("1-2*3+0x10/4"), Ok(-1));
+    }
+
+    #[test]
+    fn expr_negative_operands_work() {
+        assert_eq!(eval_literal_expr::<i32>("-2 * -3"), Ok(6));
+        assert_eq!(eval_literal_expr::<i32>("4--0x2"), Ok(6));
+        assert_eq!(eval_literal_expr::<u32>("-2 * 3"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn expr_division_by_zero_fails() {
+        assert_eq!(eval_literal_expr::<u32>("1/0"), Err(ParseLiteralError::DivisionByZero));
+        assert_eq!(eval_literal_expr::<i32>("5 + 1 / 0x0"), Err(ParseLiteralError::DivisionByZero));
+    }
+
+    #[test]
+    fn expr_overflow_fails() {
+        assert_eq!(eval_literal_expr::<u8>("16*16"), Err(ParseLiteralError::Overflow));
+        assert_eq!(eval_literal_expr::<u8>("255+1"), Err(ParseLiteralError::Overflow));
+        assert_eq!(eval_literal_expr::<u8>("1-2+3"), Err(ParseLiteralError::Overflow));
+        assert_eq!(eval_literal_expr::<i8>("-128/-1"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn power_works() {
+        assert_eq!(parse_power_literal::<u32>("2^10"), Ok(1024));
+        assert_eq!(parse_power_literal::<u32>("16^2"), Ok(256));
+        assert_eq!(parse_power_literal::<u32>(" 0x10 ^ 0b10 "), Ok(256));
+        assert_eq!(parse_power_literal::<i32>("-3^3"), Ok(-27));
+        assert_eq!(parse_power_literal::<u32>("7^0"), Ok(1));
+        assert_eq!(parse_power_literal::<u32>("42"), Ok(42));
+        assert_eq!(parse_power_literal::<u8>("2^7"), Ok(128));
+    }
+
+    #[test]
+    fn power_overflow_fails() {
+        assert_eq!(parse_power_literal::<u8>("2^8"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_power_literal::<u64>("10^20"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_power_literal::<i8>("-2^8"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn power_malformed_fails() {
+        assert_eq!(parse_power_literal::<u32>("2^-1"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parse_power_literal::<u32>("2^"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_power_literal::<u32>("^2"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_power_literal::<u32>("2^3^4"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn expr_malformed_fails() {
+        assert_eq!(eval_literal_expr::<u32>(""), Err(ParseLiteralError::Empty));
+        assert_eq!(eval_literal_expr::<u32>("2+"), Err(ParseLiteralError::Empty));
+        assert_eq!(eval_literal_expr::<u32>("2+*3"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(eval_literal_expr::<u32>("2 3"), Err(ParseLiteralError::TrailingCharacters { offset: 2 }));
+        assert_eq!(eval_literal_expr::<u32>("(2+3)"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn times_ten_works() {
+        for text in ["1.5×10^3", "1.5x10^3", "1.5X10^3", "1.5*10^3", "1.5 × 10^3", " 1.5 x 10^+3 ", "1500"] {
+            assert_eq!(parse_times_ten_literal::<f64>(text), Ok(1500.0), "{text}");
+        }
+        assert_eq!(parse_times_ten_literal::<f64>("-2.5×10^-2"), Ok(-0.025));
+        assert_eq!(parse_times_ten_literal::<f64>("6.02214076×10^23"), Ok(6.02214076e23));
+        assert_eq!(parse_times_ten_literal::<u32>("4×10^2"), Ok(400));
+        assert_eq!(parse_times_ten_literal::<u32>("1.5×10^-1"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn times_ten_malformed_fails() {
+        assert_eq!(parse_times_ten_literal::<f64>("1.5×10^"), Err(ParseLiteralError::InvalidExponent));
+        assert_eq!(parse_times_ten_literal::<f64>("1.5×10^x"), Err(ParseLiteralError::InvalidExponent));
+        assert_eq!(parse_times_ten_literal::<f64>("1.5×10^--3"), Err(ParseLiteralError::InvalidExponent));
+        assert_eq!(parse_times_ten_literal::<f64>("1.5×10^1.5"), Err(ParseLiteralError::InvalidExponent));
+        assert_eq!(parse_times_ten_literal::<f64>("2×3^2"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_times_ten_literal::<f64>("1e2×10^3"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_times_ten_literal::<f64>("×10^3"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_times_ten_literal::<f64>("0x10^3"), Err(ParseLiteralError::UnsupportedFormat));
+        assert_eq!(parse_times_ten_literal::<f64>("1.5^3"), Err(ParseLiteralError::InvalidDigit));
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use half::f16;
+use crate::{NumLiteralTrait, ParseLiteralError};
+
+/// Convert a decimal floating-point literal to a half precision float or
+/// return an error.
+///
+/// The text is parsed as `f32` by `parse_float_literal` and then rounded
+/// to the nearest `f16`. Magnitudes beyond `f16::MAX` (65504) overflow to
+/// infinity rather than erroring, and tiny magnitudes become subnormals
+/// or zero, matching `f16::from_f32`.
+///
+/// # Arguments
+/// - `text`: Textual representation of a number.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::f16;
+/// use num_literal_traits::parse_f16_literal;
+///
+/// let result = parse_f16_literal("1.5e1");
+/// assert_eq!(result, Ok(f16::from_f32(15.0)));
+///
+/// let result = parse_f16_literal("1e5");
+/// assert_eq!(result, Ok(f16::INFINITY));
+/// ```
+pub fn parse_f16_literal(text: &str) -> Result<f16, ParseLiteralError> {
+    f32::parse_float_literal(text).map(f16::from_f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f16_works() {
+        let result = parse_f16_literal("-0.333");
+        assert_eq!(result, Ok(f16::from_f32(-0.333)));
+    }
+
+    #[test]
+    fn f16_subnormal_works() {
+        let result = parse_f16_literal("5.9604645e-8").unwrap();
+        assert!(!result.is_normal() && result != f16::ZERO);
+        assert_eq!(result, f16::from_bits(1));
+    }
+
+    #[test]
+    fn f16_overflow_is_infinite() {
+        let result = parse_f16_literal("-65520");
+        assert_eq!(result, Ok(f16::NEG_INFINITY));
+    }
+
+    #[test]
+    fn f16_random_text_fails() {
+        let res = parse_f16_literal("half");
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// The textual formats a numeric literal can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LiteralFormat {
+    /// `0b100010`, `0B0`
+    Binary,
+    /// `0123`, `0o777`, `00`
+    Octal,
+    /// `123`, `0`
+    Decimal,
+    /// `0xCAFE`, `0X0`, `0hFF`
+    Hexadecimal,
+    /// `0zZ9`, `0zid`
+    Base36,
+    /// `'A'`, `'!'`
+    Char,
+}
+
+impl LiteralFormat {
+    /// The radix of the digits, or `None` for char literals.
+    pub const fn radix(self) -> Option<u32> {
+        match self {
+            Self::Binary => Some(2),
+            Self::Octal => Some(8),
+            Self::Decimal => Some(10),
+            Self::Hexadecimal => Some(16),
+            Self::Base36 => Some(36),
+            Self::Char => None,
+        }
+    }
+}
+
+/// The radix prefixes, matched case-insensitively. A leading `0` without
+/// one of these letters selects octal. Every prefix is a `0` and a
+/// letter, which the single-pass scanner relies on.
+pub(crate) const PREFIXES: [(&str, LiteralFormat); 5] = [
+    ("0b", LiteralFormat::Binary),
+    ("0o", LiteralFormat::Octal),
+    ("0x", LiteralFormat::Hexadecimal),
+    ("0h", LiteralFormat::Hexadecimal),
+    ("0z", LiteralFormat::Base36),
+];
+
+/// The Rust integer type suffixes, like the `u8` in `0xFFu8`, with the
+/// range of their type. None starts with a hexadecimal digit, so they can
+/// follow the digits of every radix but 36 unambiguously.
+pub(crate) const RUST_SUFFIXES: [(&str, i128, u128); 12] = [
+    ("u8", 0, u8::MAX as u128),
+    ("u16", 0, u16::MAX as u128),
+    ("u32", 0, u32::MAX as u128),
+    ("u64", 0, u64::MAX as u128),
+    ("u128", 0, u128::MAX),
+    ("usize", 0, usize::MAX as u128),
+    ("i8", i8::MIN as i128, i8::MAX as u128),
+    ("i16", i16::MIN as i128, i16::MAX as u128),
+    ("i32", i32::MIN as i128, i32::MAX as u128),
+    ("i64", i64::MIN as i128, i64::MAX as u128),
+    ("i128", i128::MIN, i128::MAX as u128),
+    ("isize", isize::MIN as i128, isize::MAX as u128),
+];
+
+/// The radix prefix at the start of a text and its format.
+pub(crate) const fn match_prefix(text: &str) -> Option<(&'static str, LiteralFormat)> {
+    prefix_of(text.as_bytes())
+}
+
+/// The radix prefix at the start of bytes and its format.
+pub(crate) const fn prefix_of(bytes: &[u8]) -> Option<(&'static str, LiteralFormat)> {
+    let [b'0', letter, ..] = bytes else {
+        return None;
+    };
+    let mut i = 0;
+    while i < PREFIXES.len() {
+        if PREFIXES[i].0.as_bytes()[1] == letter.to_ascii_lowercase() {
+            return Some(PREFIXES[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Determine the literal format of a text from its prefix.
+///
+/// Only the prefix is inspected, the digits are not validated, so
+/// `"CAFE"` is reported as `Decimal` even though it fails to parse.
+///
+/// # Arguments
+/// - `text`: Textual representation of a number.
+/// # Returns
+/// - The format `parse_literal` would parse the text as.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{detect_format, LiteralFormat};
+///
+/// assert_eq!(detect_format("0xCAFE"), LiteralFormat::Hexadecimal);
+/// assert_eq!(detect_format("0755"), LiteralFormat::Octal);
+/// assert_eq!(detect_format("'A'"), LiteralFormat::Char);
+/// ```
+pub fn detect_format(text: &str) -> LiteralFormat {
+    let text = text.trim();
+    if let Some((_, format)) = match_prefix(text) {
+        return format;
+    }
+    match text.as_bytes() {
+        [b'\'', _, b'\''] => LiteralFormat::Char,
+        [b'0', _, ..] => LiteralFormat::Octal,
+        _ => LiteralFormat::Decimal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_prefixes_works() {
+        assert_eq!(detect_format("0B11"), LiteralFormat::Binary);
+        assert_eq!(detect_format("00"), LiteralFormat::Octal);
+        assert_eq!(detect_format("0O17"), LiteralFormat::Octal);
+        assert_eq!(detect_format(" 0x1f "), LiteralFormat::Hexadecimal);
+        assert_eq!(detect_format("0Zz"), LiteralFormat::Base36);
+        assert_eq!(detect_format("0HFF"), LiteralFormat::Hexadecimal);
+    }
+
+    #[test]
+    fn detect_unprefixed_is_decimal() {
+        assert_eq!(detect_format("0"), LiteralFormat::Decimal);
+        assert_eq!(detect_format("CAFE"), LiteralFormat::Decimal);
+    }
+
+    #[test]
+    fn detect_char_works() {
+        assert_eq!(detect_format("'0'"), LiteralFormat::Char);
+        assert_eq!(detect_format("'AB'"), LiteralFormat::Decimal);
+    }
+}
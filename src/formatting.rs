@@ -0,0 +1,358 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::LiteralFormat;
+
+/// Integer types that can be formatted back into a literal.
+pub trait LiteralInteger: Copy {
+    /// Split the value into whether it is negative and its magnitude.
+    fn sign_magnitude(self) -> (bool, u128);
+}
+
+macro_rules! impl_literal_signed {
+    ($($t:ty)*) => {$(
+        impl LiteralInteger for $t {
+            fn sign_magnitude(self) -> (bool, u128) {
+                (self < 0, self.unsigned_abs() as u128)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_literal_unsigned {
+    ($($t:ty)*) => {$(
+        impl LiteralInteger for $t {
+            fn sign_magnitude(self) -> (bool, u128) {
+                (false, self as u128)
+            }
+        }
+    )*};
+}
+
+impl_literal_signed!(i8 i16 i32 i64 i128 isize);
+impl_literal_unsigned!(u8 u16 u32 u64 u128 usize);
+
+/// Options controlling how [`format_literal_with`] writes a literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    uppercase: bool,
+    prefix: bool,
+    grouping: bool,
+    binary_group: usize,
+    octal_group: usize,
+    decimal_group: usize,
+    hex_group: usize,
+    base36_group: usize,
+}
+
+impl FormatOptions {
+    /// Options for uppercase hex digits with a radix prefix and no digit
+    /// grouping.
+    pub fn new() -> Self {
+        Self {
+            uppercase: true,
+            prefix: true,
+            grouping: false,
+            binary_group: 4,
+            octal_group: 3,
+            decimal_group: 3,
+            hex_group: 4,
+            base36_group: 4,
+        }
+    }
+
+    /// Write hex and base-36 digits as uppercase (default) or lowercase
+    /// letters.
+    #[must_use]
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Write the radix prefix `0b`, `0`, `0x` or `0z` (default) or only
+    /// the digits. Without the prefix the result does not parse back into
+    /// the same value.
+    #[must_use]
+    pub fn prefix(mut self, prefix: bool) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Separate groups of digits with `_`, counted from the least
+    /// significant digit, like `0xDEAD_BEEF` or `1_000_000`. Disabled by
+    /// default. Groups hold 4 binary, hex or base-36 digits and 3 octal or
+    /// decimal digits unless changed with [`group_size`](Self::group_size).
+    #[must_use]
+    pub fn grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Set the number of digits per group for one format, where `0`
+    /// disables grouping for that format. Char literals are never grouped.
+    #[must_use]
+    pub fn group_size(mut self, format: LiteralFormat, size: usize) -> Self {
+        match format {
+            LiteralFormat::Binary => self.binary_group = size,
+            LiteralFormat::Octal => self.octal_group = size,
+            LiteralFormat::Decimal => self.decimal_group = size,
+            LiteralFormat::Hexadecimal => self.hex_group = size,
+            LiteralFormat::Base36 => self.base36_group = size,
+            LiteralFormat::Char => {}
+        }
+        self
+    }
+
+    fn group_size_of(&self, format: LiteralFormat) -> usize {
+        if !self.grouping {
+            return 0;
+        }
+        match format {
+            LiteralFormat::Binary => self.binary_group,
+            LiteralFormat::Octal => self.octal_group,
+            LiteralFormat::Decimal => self.decimal_group,
+            LiteralFormat::Hexadecimal => self.hex_group,
+            LiteralFormat::Base36 => self.base36_group,
+            LiteralFormat::Char => 0,
+        }
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The char literal for a value, if it is a printable ASCII character.
+pub(crate) fn char_literal(value: u128) -> Option<String> {
+    u8::try_from(value).ok()
+        .filter(|b| b.is_ascii_graphic() || *b == b' ')
+        .map(|b| format!("'{}'", char::from(b)))
+}
+
+/// Digits of a value in radix 36, most significant first.
+fn base36_digits(mut magnitude: u128, uppercase: bool) -> String {
+    let mut digits = Vec::new();
+    loop {
+        let digit = char::from_digit((magnitude % 36) as u32, 36).unwrap();
+        digits.push(if uppercase { digit.to_ascii_uppercase() } else { digit });
+        magnitude /= 36;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// Insert `_` between groups of `size` digits, counted from the right.
+fn group_digits(digits: String, size: usize) -> String {
+    if size == 0 || digits.len() <= size {
+        return digits;
+    }
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / size);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(size) {
+            grouped.push('_');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Format an integer as a literal in the given format.
+///
+/// # Arguments
+/// - `value`: Integer to format.
+/// - `format`: Literal format to write.
+/// # Returns
+/// - Literal that `parse_literal` converts back into `value`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{format_literal, LiteralFormat};
+///
+/// assert_eq!(format_literal(255u8, LiteralFormat::Hexadecimal), "0xFF");
+/// assert_eq!(format_literal(8u32, LiteralFormat::Octal), "010");
+/// assert_eq!(format_literal(65u32, LiteralFormat::Char), "'A'");
+/// ```
+pub fn format_literal<T: LiteralInteger>(value: T, format: LiteralFormat) -> String {
+    format_literal_with(value, format, FormatOptions::default())
+}
+
+/// Format an integer as a literal in the given format and style.
+///
+/// Negative values are written with a leading `-` followed by the
+/// magnitude. Values without a printable ASCII character are written as
+/// decimal when `Char` is requested.
+///
+/// # Arguments
+/// - `value`: Integer to format.
+/// - `format`: Literal format to write.
+/// - `options`: Digit case, prefix and grouping style.
+/// # Returns
+/// - Textual representation of the value.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{format_literal_with, FormatOptions, LiteralFormat};
+///
+/// let options = FormatOptions::new().uppercase(false);
+/// assert_eq!(format_literal_with(0xCAFEu32, LiteralFormat::Hexadecimal, options), "0xcafe");
+///
+/// let options = FormatOptions::new().prefix(false);
+/// assert_eq!(format_literal_with(5u32, LiteralFormat::Binary, options), "101");
+///
+/// let options = FormatOptions::new().grouping(true);
+/// assert_eq!(format_literal_with(0xDEADBEEFu32, LiteralFormat::Hexadecimal, options), "0xDEAD_BEEF");
+/// assert_eq!(format_literal_with(1000000u32, LiteralFormat::Decimal, options), "1_000_000");
+/// ```
+pub fn format_literal_with<T: LiteralInteger>(value: T, format: LiteralFormat, options: FormatOptions) -> String {
+    let (negative, magnitude) = value.sign_magnitude();
+    let sign = if negative { "-" } else { "" };
+    let (prefix, digits) = match format {
+        LiteralFormat::Binary => ("0b", format!("{magnitude:b}")),
+        LiteralFormat::Octal if magnitude == 0 => ("", "0".to_string()),
+        LiteralFormat::Octal => ("0", format!("{magnitude:o}")),
+        LiteralFormat::Decimal => ("", magnitude.to_string()),
+        LiteralFormat::Hexadecimal if options.uppercase => ("0x", format!("{magnitude:X}")),
+        LiteralFormat::Hexadecimal => ("0x", format!("{magnitude:x}")),
+        LiteralFormat::Base36 => ("0z", base36_digits(magnitude, options.uppercase)),
+        LiteralFormat::Char => match char_literal(magnitude) {
+            Some(literal) if !negative => return literal,
+            _ => return format_literal_with(value, LiteralFormat::Decimal, options),
+        },
+    };
+    let prefix = if options.prefix { prefix } else { "" };
+    let digits = group_digits(digits, options.group_size_of(format));
+    format!("{sign}{prefix}{digits}")
+}
+
+/// Format an integer as a hexadecimal literal, like `0xFF`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::to_hex_literal;
+///
+/// assert_eq!(to_hex_literal(0xDEADu16), "0xDEAD");
+/// ```
+pub fn to_hex_literal<T: LiteralInteger>(value: T) -> String {
+    format_literal(value, LiteralFormat::Hexadecimal)
+}
+
+/// Format an integer as a binary literal, like `0b101`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::to_binary_literal;
+///
+/// assert_eq!(to_binary_literal(5u8), "0b101");
+/// ```
+pub fn to_binary_literal<T: LiteralInteger>(value: T) -> String {
+    format_literal(value, LiteralFormat::Binary)
+}
+
+/// Format an integer as an octal literal, like `0755`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::to_octal_literal;
+///
+/// assert_eq!(to_octal_literal(493u32), "0755");
+/// ```
+pub fn to_octal_literal<T: LiteralInteger>(value: T) -> String {
+    format_literal(value, LiteralFormat::Octal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NumLiteralTrait;
+
+    const FORMATS: [LiteralFormat; 6] = [
+        LiteralFormat::Binary,
+        LiteralFormat::Octal,
+        LiteralFormat::Decimal,
+        LiteralFormat::Hexadecimal,
+        LiteralFormat::Base36,
+        LiteralFormat::Char,
+    ];
+
+    #[test]
+    fn format_round_trip_works() {
+        for value in [0u64, 1, 7, 8, 33, 65, 126, 127, 0xCAFE, u64::MAX] {
+            for format in FORMATS {
+                let text = format_literal(value, format);
+                assert_eq!(u64::parse_literal(&text), Ok(value), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn format_negative_round_trip_works() {
+        for value in [-1i64, -65, i64::MIN] {
+            for format in FORMATS {
+                let text = format_literal(value, format);
+                assert_eq!(i64::parse_literal(&text), Ok(value), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn format_helpers_work() {
+        assert_eq!(to_hex_literal(-255i16), "-0xFF");
+        assert_eq!(to_binary_literal(0u8), "0b0");
+        assert_eq!(to_octal_literal(0u8), "0");
+        assert_eq!(format_literal(10u8, LiteralFormat::Char), "10");
+        assert_eq!(format_literal(1295u32, LiteralFormat::Base36), "0zZZ");
+        assert_eq!(format_literal(0u32, LiteralFormat::Base36), "0z0");
+    }
+
+    #[test]
+    fn format_options_work() {
+        let options = FormatOptions::new().uppercase(false).prefix(false);
+        assert_eq!(format_literal_with(0xABu8, LiteralFormat::Hexadecimal, options), "ab");
+        assert_eq!(format_literal_with(8u8, LiteralFormat::Octal, options), "10");
+    }
+
+    #[test]
+    fn format_grouping_works() {
+        let options = FormatOptions::new().grouping(true);
+        assert_eq!(format_literal_with(0xDEADBEEFu32, LiteralFormat::Hexadecimal, options), "0xDEAD_BEEF");
+        assert_eq!(format_literal_with(0x1BEEFu32, LiteralFormat::Hexadecimal, options), "0x1_BEEF");
+        assert_eq!(format_literal_with(-1234567i32, LiteralFormat::Decimal, options), "-1_234_567");
+        assert_eq!(format_literal_with(999u32, LiteralFormat::Decimal, options), "999");
+        assert_eq!(format_literal_with(0o7777u32, LiteralFormat::Octal, options), "07_777");
+    }
+
+    #[test]
+    fn format_group_size_works() {
+        let options = FormatOptions::new()
+            .grouping(true)
+            .group_size(LiteralFormat::Hexadecimal, 2)
+            .group_size(LiteralFormat::Decimal, 0);
+        assert_eq!(format_literal_with(0xDEADBEEFu32, LiteralFormat::Hexadecimal, options), "0xDE_AD_BE_EF");
+        assert_eq!(format_literal_with(1000000u32, LiteralFormat::Decimal, options), "1000000");
+    }
+
+    #[test]
+    fn format_grouping_round_trip_works() {
+        let options = FormatOptions::new().grouping(true);
+        for value in [0u64, 4095, 0xDEADBEEF, 1000000, u64::MAX] {
+            for format in FORMATS {
+                let text = format_literal_with(value, format, options);
+                assert_eq!(u64::parse_literal(&text), Ok(value), "{text}");
+            }
+        }
+    }
+}
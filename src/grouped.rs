@@ -0,0 +1,292 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::{NumLiteralTrait, ParseLiteralError};
+use crate::parse_literal::split_sign;
+
+/// Remove the group separators from the integer digits of a number,
+/// checking that they split the digits into thousands.
+fn strip_groups(digits: &str, group: char) -> Result<String, ParseLiteralError> {
+    let mut groups = digits.split(group);
+    let first = groups.next().unwrap_or_default();
+    if digits.contains(group) && (!(1..=3).contains(&first.len()) || groups.any(|group| group.len() != 3)) {
+        return Err(ParseLiteralError::InvalidGrouping);
+    }
+    Ok(digits.replace(group, ""))
+}
+
+/// Convert a decimal number with thousands separators, like `1,234.56`,
+/// to a number value or return an error.
+///
+/// The separators are given for the locale: `','` and `'.'` for US
+/// style `1,234.56`, or `'.'` and `','` for European style `1.234,56`.
+/// Group separators are only allowed in the integer part, between every
+/// three digits counted from the decimal separator, so `1,23.5` and a
+/// group separator in the fraction, like in `1.234,56` read as US style,
+/// are an `InvalidGrouping` error. Digits without separators are fine. A
+/// fraction is converted like in `parse_float_literal`, exponents are
+/// not supported.
+///
+/// # Arguments
+/// - `text`: Decimal number with optional thousands separators.
+/// - `group`: Separator between groups of thousands.
+/// - `decimal`: Separator between the integer and fractional part.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_grouped_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_grouped_literal::<f64>("1,234.567", ',', '.'), Ok(1234.567));
+/// assert_eq!(parse_grouped_literal::<f64>("1.234,56", '.', ','), Ok(1234.56));
+/// assert_eq!(parse_grouped_literal::<f64>("1.234,56", ',', '.'), Err(ParseLiteralError::InvalidGrouping));
+/// assert_eq!(parse_grouped_literal::<u32>("1,000,000", ',', '.'), Ok(1_000_000));
+/// ```
+pub fn parse_grouped_literal<T: Num>(text: &str, group: char, decimal: char) -> Result<T, ParseLiteralError> {
+    let (negative, text) = split_sign(text.trim())?;
+    let (int_part, frac_part) = match text.split_once(decimal) {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (text, None),
+    };
+    if frac_part.is_some_and(|frac_part| frac_part.contains(group)) {
+        return Err(ParseLiteralError::InvalidGrouping);
+    }
+    let int_digits = strip_groups(int_part, group)?;
+    let digits = match frac_part {
+        Some(frac_part) => format!("{int_digits}.{frac_part}"),
+        None => int_digits,
+    };
+    if !digits.bytes().all(|byte| byte.is_ascii_digit() || byte == b'.') || digits.matches('.').count() > 1 {
+        return Err(ParseLiteralError::InvalidDigit);
+    }
+    T::parse_float_literal(if negative { format!("-{digits}") } else { digits })
+}
+
+/// Convert a decimal number with thousands separators in an unknown
+/// locale, like `1,234.56` or `1.234,56`, to a number value or return an
+/// error.
+///
+/// The separators are inferred from the text, then it is parsed like
+/// `parse_grouped_literal`:
+/// - With both `.` and `,`, the last one is the decimal separator.
+/// - A separator that occurs more than once, like in `1.234.567`, groups
+///   thousands.
+/// - A single separator is the decimal separator, like in `1,5`, unless
+///   exactly three digits follow it. That case is ambiguous and follows
+///   the English convention: `1,234` is 1234 and `1.234` is 1.234.
+///
+/// # Arguments
+/// - `text`: Decimal number with optional thousands separators.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_grouped_literal_auto;
+///
+/// assert_eq!(parse_grouped_literal_auto::<f64>("1,234.56"), Ok(1234.56));
+/// assert_eq!(parse_grouped_literal_auto::<f64>("1.234,56"), Ok(1234.56));
+/// assert_eq!(parse_grouped_literal_auto::<f64>("1,234"), Ok(1234.0));
+/// assert_eq!(parse_grouped_literal_auto::<f64>("1,5"), Ok(1.5));
+/// ```
+pub fn parse_grouped_literal_auto<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let (group, decimal) = match (text.rfind('.'), text.rfind(',')) {
+        (Some(point), Some(comma)) if point > comma => (',', '.'),
+        (Some(_), Some(_)) => ('.', ','),
+        (Some(_), None) => single_separator(text, '.'),
+        (None, Some(_)) => single_separator(text, ','),
+        (None, None) => (',', '.'),
+    };
+    parse_grouped_literal(text, group, decimal)
+}
+
+/// Infer the meaning of the only kind of separator in a number, and
+/// return the group and decimal separator.
+fn single_separator(text: &str, separator: char) -> (char, char) {
+    let other = if separator == '.' { ',' } else { '.' };
+    let mut parts = text.trim().split(separator).skip(1);
+    let grouping = match (parts.next(), parts.next()) {
+        (_, Some(_)) => true,
+        (Some(fraction), None) => fraction.len() == 3 && separator == ',',
+        (None, None) => false,
+    };
+    if grouping { (separator, other) } else { (other, separator) }
+}
+
+/// Convert an amount in accounting notation, like `($1,234.50)`, to a
+/// number value or return an error.
+///
+/// Parentheses around the amount mark it negative, the way ledgers
+/// write losses. They are stripped first, then an optional `currency`
+/// symbol at the start, then the rest is parsed like
+/// `parse_grouped_literal` in US style, with `,` between thousands and
+/// `.` before the fraction. Whitespace is allowed inside the parentheses
+/// and after the symbol. Outside of parentheses, a sign may precede the
+/// symbol, like in `-$5`, but a sign inside parentheses or after the
+/// symbol is an `InvalidSign` error, and an amount without digits, like
+/// `($)`, is an `Empty` error. Unbalanced parentheses are an
+/// `InvalidDigit` error.
+///
+/// # Arguments
+/// - `text`: Amount, optionally in parentheses and with a currency symbol.
+/// - `currency`: Currency symbol that may precede the digits, or `""`.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_accounting_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_accounting_literal::<f64>("($1,234.50)", "$"), Ok(-1234.5));
+/// assert_eq!(parse_accounting_literal::<f64>("$1,234.50", "$"), Ok(1234.5));
+/// assert_eq!(parse_accounting_literal::<i32>("(€ 20)", "€"), Ok(-20));
+/// assert_eq!(parse_accounting_literal::<f64>("($)", "$"), Err(ParseLiteralError::Empty));
+/// ```
+pub fn parse_accounting_literal<T: Num>(text: &str, currency: &str) -> Result<T, ParseLiteralError> {
+    let text = text.trim();
+    let (parenthesized, body) = match text.strip_prefix('(') {
+        Some(inner) => (true, inner.strip_suffix(')').ok_or(ParseLiteralError::InvalidDigit)?.trim()),
+        None if text.ends_with(')') => return Err(ParseLiteralError::InvalidDigit),
+        None => (false, text),
+    };
+    if parenthesized && body.starts_with(['+', '-']) {
+        return Err(ParseLiteralError::InvalidSign);
+    }
+    let (negative, body) = split_sign(body)?;
+    let amount = body.strip_prefix(currency).unwrap_or(body).trim_start();
+    if amount.starts_with(['+', '-']) {
+        return Err(ParseLiteralError::InvalidSign);
+    }
+    if amount.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    let sign = if parenthesized || negative { "-" } else { "" };
+    parse_grouped_literal(&format!("{sign}{amount}"), ',', '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grouped_us_works() {
+        assert_eq!(parse_grouped_literal::<f64>("1,234.56", ',', '.'), Ok(1234.56));
+        assert_eq!(parse_grouped_literal::<f64>("1,234.567", ',', '.'), Ok(1234.567));
+        assert_eq!(parse_grouped_literal::<f64>("-12,345,678.9", ',', '.'), Ok(-12_345_678.9));
+        assert_eq!(parse_grouped_literal::<f64>("123.4567", ',', '.'), Ok(123.4567));
+        assert_eq!(parse_grouped_literal::<f64>(" 1234.5 ", ',', '.'), Ok(1234.5));
+    }
+
+    #[test]
+    fn grouped_european_works() {
+        assert_eq!(parse_grouped_literal::<f64>("1.234,56", '.', ','), Ok(1234.56));
+        assert_eq!(parse_grouped_literal::<f64>("1.234.567,891", '.', ','), Ok(1_234_567.891));
+        assert_eq!(parse_grouped_literal::<f64>("1 234,5", ' ', ','), Ok(1234.5));
+    }
+
+    #[test]
+    fn grouped_integers_work() {
+        assert_eq!(parse_grouped_literal::<u32>("1,000,000", ',', '.'), Ok(1_000_000));
+        assert_eq!(parse_grouped_literal::<i32>("-999", ',', '.'), Ok(-999));
+        assert_eq!(parse_grouped_literal::<u8>("1,000", ',', '.'), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_grouped_literal::<u32>("1,234.5", ',', '.'), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn grouped_misplaced_separator_fails() {
+        for text in ["1.234,56", "1,234.5,6", "1,23.5", "1,2345.6", ",123.4", "1,,234", "1234,", "1,234,56.7"] {
+            assert_eq!(parse_grouped_literal::<f64>(text, ',', '.'), Err(ParseLiteralError::InvalidGrouping), "{text}");
+        }
+    }
+
+    #[test]
+    fn grouped_malformed_fails() {
+        assert_eq!(parse_grouped_literal::<f64>("1,234.5.6", ',', '.'), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_grouped_literal::<f64>("1,23a.5", ',', '.'), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_grouped_literal::<f64>("1e3", ',', '.'), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_grouped_literal::<f64>("", ',', '.'), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_grouped_literal::<f64>("-", ',', '.'), Err(ParseLiteralError::LoneSign));
+    }
+
+    #[test]
+    fn grouped_auto_works() {
+        let cases = [
+            ("1,234.56", 1234.56),
+            ("1.234,56", 1234.56),
+            ("12,345,678.9", 12_345_678.9),
+            ("12.345.678,9", 12_345_678.9),
+            ("1.234.567", 1_234_567.0),
+            ("1,234,567", 1_234_567.0),
+            ("1,5", 1.5),
+            ("2.75", 2.75),
+            ("-0,25", -0.25),
+            ("1234", 1234.0),
+        ];
+        for (text, value) in cases {
+            assert_eq!(parse_grouped_literal_auto::<f64>(text), Ok(value), "{text}");
+        }
+        assert_eq!(parse_grouped_literal_auto::<u32>("1.234.567"), Ok(1_234_567));
+    }
+
+    #[test]
+    fn grouped_auto_ambiguous_follows_english() {
+        assert_eq!(parse_grouped_literal_auto::<f64>("1,234"), Ok(1234.0));
+        assert_eq!(parse_grouped_literal_auto::<f64>("1.234"), Ok(1.234));
+        assert_eq!(parse_grouped_literal_auto::<u32>("1,234"), Ok(1234));
+    }
+
+    #[test]
+    fn grouped_auto_malformed_fails() {
+        assert_eq!(parse_grouped_literal_auto::<f64>("1,23,4.5"), Err(ParseLiteralError::InvalidGrouping));
+        assert_eq!(parse_grouped_literal_auto::<f64>("1.2.3"), Err(ParseLiteralError::InvalidGrouping));
+        assert_eq!(parse_grouped_literal_auto::<f64>("1.234,5.6"), Err(ParseLiteralError::InvalidGrouping));
+        assert_eq!(parse_grouped_literal_auto::<f64>("1,2a4"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_grouped_literal_auto::<f64>(""), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn accounting_works() {
+        assert_eq!(parse_accounting_literal::<f64>("($1,234.50)", "$"), Ok(-1234.5));
+        assert_eq!(parse_accounting_literal::<f64>(" ( $ 1,234.50 ) ", "$"), Ok(-1234.5));
+        assert_eq!(parse_accounting_literal::<f64>("$1,234.50", "$"), Ok(1234.5));
+        assert_eq!(parse_accounting_literal::<f64>("-$1,234.50", "$"), Ok(-1234.5));
+        assert_eq!(parse_accounting_literal::<f64>("(1,234.50)", "$"), Ok(-1234.5));
+        assert_eq!(parse_accounting_literal::<f64>("(1,234.50)", ""), Ok(-1234.5));
+        assert_eq!(parse_accounting_literal::<f64>("(USD 0.99)", "USD"), Ok(-0.99));
+        assert_eq!(parse_accounting_literal::<i64>("($1,000,000)", "$"), Ok(-1_000_000));
+        assert_eq!(parse_accounting_literal::<u32>("$1,000", "$"), Ok(1000));
+    }
+
+    #[test]
+    fn accounting_malformed_fails() {
+        let cases = [
+            ("($)", ParseLiteralError::Empty),
+            ("()", ParseLiteralError::Empty),
+            ("$", ParseLiteralError::Empty),
+            ("(-$5)", ParseLiteralError::InvalidSign),
+            ("($-5)", ParseLiteralError::InvalidSign),
+            ("$-5", ParseLiteralError::InvalidSign),
+            ("-($5)", ParseLiteralError::InvalidDigit),
+            ("($5", ParseLiteralError::InvalidDigit),
+            ("$5)", ParseLiteralError::InvalidDigit),
+            ("(($5))", ParseLiteralError::InvalidDigit),
+            ("5$", ParseLiteralError::InvalidDigit),
+            ("€5", ParseLiteralError::InvalidDigit),
+            ("($1,23.50)", ParseLiteralError::InvalidGrouping),
+        ];
+        for (text, error) in cases {
+            assert_eq!(parse_accounting_literal::<f64>(text, "$"), Err(error), "{text}");
+        }
+        assert_eq!(parse_accounting_literal::<u32>("($5)", "$"), Err(ParseLiteralError::InvalidSign));
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::{NumLiteralTrait, ParseLiteralError};
+
+/// Split a `key=value` pair and convert the value as a literal, or
+/// return an error.
+///
+/// The key and value are trimmed. A text without the separator, with
+/// more than one separator or with an empty key is an `InvalidKeyValue`
+/// error, and an empty value is an `Empty` error.
+///
+/// # Arguments
+/// - `text`: Key and literal value, split by `sep`.
+/// - `sep`: Separator between key and value.
+/// # Returns
+/// - The key and numerical result, or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_kv_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_kv_literal::<u16>("port=0x1F90", '='), Ok(("port", 8080)));
+/// assert_eq!(parse_kv_literal::<u16>("port: 8080", ':'), Ok(("port", 8080)));
+/// assert_eq!(parse_kv_literal::<u16>("port=", '='), Err(ParseLiteralError::Empty));
+/// ```
+pub fn parse_kv_literal<T: Num>(text: &str, sep: char) -> Result<(&str, T), ParseLiteralError> {
+    let (key, value) = text.split_once(sep).ok_or(ParseLiteralError::InvalidKeyValue)?;
+    let key = key.trim();
+    if key.is_empty() || value.contains(sep) {
+        return Err(ParseLiteralError::InvalidKeyValue);
+    }
+    Ok((key, T::parse_literal(value)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kv_works() {
+        assert_eq!(parse_kv_literal::<u16>("port=8080", '='), Ok(("port", 8080)));
+        assert_eq!(parse_kv_literal::<u16>(" port = 0x1F90 ", '='), Ok(("port", 8080)));
+        assert_eq!(parse_kv_literal::<i8>("offset:-0b11", ':'), Ok(("offset", -3)));
+    }
+
+    #[test]
+    fn kv_missing_value_fails() {
+        assert_eq!(parse_kv_literal::<u16>("port=", '='), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_kv_literal::<u16>("port=  ", '='), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn kv_malformed_fails() {
+        for text in ["port", "port 8080", "=8080", "port==8080", "port=80=80"] {
+            assert_eq!(parse_kv_literal::<u16>(text, '='), Err(ParseLiteralError::InvalidKeyValue), "{text}");
+        }
+    }
+
+    #[test]
+    fn kv_invalid_value_fails() {
+        assert_eq!(parse_kv_literal::<u8>("port=8080", '='), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_kv_literal::<u16>("port=http", '='), Err(ParseLiteralError::InvalidDigit));
+    }
+}
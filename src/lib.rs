@@ -11,10 +11,47 @@
 //! ## Compatibility
 //!
 //! The `num-literal-traits` crate is tested for rustc 1.88 and greater.
-use num_traits::Num;
+//!
+//! ## Decimal
+//!
+//! With the `decimal` feature enabled, the crate is tested against
+//! `rust_decimal::Decimal`, which implements `num_traits::Num` and so gets
+//! the trait through the blanket implementation. All integer literal
+//! formats of `parse_literal` yield whole decimals, while
+//! `parse_float_literal` keeps the written scale, so `"1.50"` stays
+//! `1.50`. Values outside the 96-bit mantissa of `Decimal` are an error,
+//! and like for floats, `inf` and `NaN` are an `InvalidDigit` error.
+//!
+//! ## Half precision
+//!
+//! With the `half` feature enabled, `parse_f16_literal` converts decimal
+//! and scientific notation into `half::f16` by way of `f32`.
+//!
+//! ## Macros
+//!
+//! With the `macros` feature enabled, `parse_literal!("0xCAFE" as u32)`
+//! converts an integer literal while compiling, so a typo in a constant
+//! table is a compile error instead of a runtime failure. The macro
+//! follows the rules of `parse_literal` and works in constant context.
+//! Without any feature, `literal_consts!` declares a batch of named
+//! integer constants from literals the same way.
+//!
+//! ## Standard library
+//!
+//! The default `std` feature converts `ParseLiteralError` into
+//! `std::io::Error` of kind `InvalidData`, for code propagating failures
+//! as `io::Result`.
+use num_traits::{Bounded, Num};
 
 /// The trait adds String parsing functions to types already implementing
 /// the num_traits::Num trait.
+///
+/// Failures are reported as `ParseLiteralError`, which distinguishes
+/// malformed input from values that do not fit the target type.
+///
+/// Methods returning only a value accept any `AsRef<str>`, like a
+/// `&String` or `Cow<str>`, without converting first. Methods returning
+/// parts of the text take a `&str` to borrow from.
 pub trait NumLiteralTrait<T: Num>: Num {
     
     /// Determine the literal type, then convert to a number value or
@@ -29,7 +66,7 @@ pub trait NumLiteralTrait<T: Num>: Num {
     ///
     /// ```rust
     /// use num_traits::Num;
-    /// use num_literal_traits::NumLiteralTrait;
+    /// use num_literal_traits::{NumLiteralTrait, ParseLiteralError};
     ///
     /// let result = u32::parse_literal("0xCAFE");
     /// assert_eq!(result, Ok(0xcafe));
@@ -37,26 +74,260 @@ pub trait NumLiteralTrait<T: Num>: Num {
     /// let result = u32::parse_literal("0b1000_0001_1111_1010");
     /// assert_eq!(result, Ok(33274));
     ///
+    /// let result = u8::parse_literal("0x1FF");
+    /// assert_eq!(result, Err(ParseLiteralError::Overflow));
+    ///
     /// let result = u32::parse_literal("CAFE");
-    /// assert!(result.is_err());
+    /// assert_eq!(result, Err(ParseLiteralError::InvalidDigit));
     /// 
     /// let result = u32::parse_literal("'A'");
     /// assert_eq!(result, Ok(65));
+    ///
+    /// let text = String::from("0o777");
+    /// let result = u32::parse_literal(&text);
+    /// assert_eq!(result, Ok(511));
     /// ```
     ///
     /// # Supported formats
     ///
     /// Most integer literal formats found in C and C++ are supported:
     /// Binary      : `0b100010`, `0B0`, `0b10101101`
-    /// Octal       : `0123`, `00`, `04763523`
+    /// Octal       : `0123`, `00`, `04763523`, `0o777`
     /// Decimal     : `123`, `0`, `7635223`    
-    /// Hexadecimal : `0xCAFE`, `0x0`, `0xa1fb484`
+    /// Hexadecimal : `0xCAFE`, `0x0`, `0xa1fb484`, `0hFF`
     /// Char        : `'A'`, `'!'`
     ///
+    /// As well as base 36 with digits `0`-`9` and `A`-`Z`:
+    /// Base36      : `0zZ9`, `0Zid`
+    ///
     /// Additionally, the numeric parts can contain underscores `_` to
-    /// which get removed before converting.    
-    fn parse_literal(text: &str) -> Result<T, T::FromStrRadixErr>;
+    /// which get removed before converting.
+    ///
+    /// Numeric formats may start with a single `+` or `-`, like `-0xFF`.
+    /// A sign on its own is a `LoneSign` error, a doubled sign like `--5`
+    /// or a `-` for an unsigned type is an `InvalidSign` error.
+    fn parse_literal(text: impl AsRef<str>) -> Result<T, ParseLiteralError>;
     
+    /// Convert the literal at the start of a text to a number value and
+    /// report how many bytes it spans, or return an error.
+    ///
+    /// The literal ends at the first character that cannot be part of it,
+    /// so the rest of the text can be handed on to an expression parser.
+    /// Leading whitespace and signs are not part of the literal.
+    ///
+    /// # Arguments
+    /// - `text`: Text starting with a numeric literal.
+    /// # Returns
+    /// - Numerical result and the number of bytes consumed, or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::NumLiteralTrait;
+    ///
+    /// let result = u32::parse_literal_prefix("0xFF+3");
+    /// assert_eq!(result, Ok((255, 4)));
+    ///
+    /// let result = u32::parse_literal_prefix("'A', 'B'");
+    /// assert_eq!(result, Ok((65, 3)));
+    ///
+    /// let result = u32::parse_literal_prefix("x + 1");
+    /// assert!(result.is_err());
+    /// ```
+    fn parse_literal_prefix(text: impl AsRef<str>) -> Result<(T, usize), ParseLiteralError>;
+
+    /// Convert the literal at the start of a text to a number value and
+    /// return the rest of the text after it, or return an error.
+    ///
+    /// Like `parse_literal_prefix`, but returns the remaining text instead
+    /// of a length, as handy for recursive-descent parsers. The rest keeps
+    /// any whitespace that follows the literal.
+    ///
+    /// # Arguments
+    /// - `text`: Text starting with a numeric literal.
+    /// # Returns
+    /// - Numerical result and the text after the literal, or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::NumLiteralTrait;
+    ///
+    /// let result = u32::parse_literal_rest("0xFF rest");
+    /// assert_eq!(result, Ok((255, " rest")));
+    /// ```
+    fn parse_literal_rest(text: &str) -> Result<(T, &str), ParseLiteralError>;
+
+    /// Convert a text holding exactly one literal to a number value or
+    /// return an error.
+    ///
+    /// Like `parse_literal`, but the literal ends where
+    /// `parse_literal_prefix` would stop, so anything after it other than
+    /// whitespace is a `TrailingCharacters` error pointing at the byte
+    /// offset of the first unexpected character.
+    ///
+    /// # Arguments
+    /// - `text`: Textual representation of a number.
+    /// # Returns
+    /// - Numerical result or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::{NumLiteralTrait, ParseLiteralError};
+    ///
+    /// let result = u32::parse_literal_all(" 0xFF ");
+    /// assert_eq!(result, Ok(255));
+    ///
+    /// let result = u32::parse_literal_all("0xFF junk");
+    /// assert_eq!(result, Err(ParseLiteralError::TrailingCharacters { offset: 5 }));
+    /// ```
+    fn parse_literal_all(text: impl AsRef<str>) -> Result<T, ParseLiteralError>;
+
+    /// Convert a literal led by a radix word, like `hex FF`, to a number
+    /// value or return an error.
+    ///
+    /// The words `bin`, `oct`, `dec` and `hex`, in any case and followed
+    /// by whitespace, select the radix of the digits after them, which
+    /// may have a sign and underscores but no radix prefix. A text
+    /// without a known word is parsed like `parse_literal`.
+    ///
+    /// # Arguments
+    /// - `text`: Textual representation of a number.
+    /// # Returns
+    /// - Numerical result or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::NumLiteralTrait;
+    ///
+    /// assert_eq!(u32::parse_literal_worded("hex FF"), Ok(255));
+    /// assert_eq!(u32::parse_literal_worded("BIN 1010"), Ok(10));
+    /// assert_eq!(u32::parse_literal_worded("0x10"), Ok(16));
+    /// ```
+    fn parse_literal_worded(text: impl AsRef<str>) -> Result<T, ParseLiteralError>;
+
+    /// Convert bare digits in the first of several radixes they are valid
+    /// in to a number value or return an error.
+    ///
+    /// The radixes are tried in the given order and the first successful
+    /// conversion is returned, so `"10"` is 16 for `&[16, 10]` but 10 for
+    /// `&[10, 16]`. The digits may have a sign and underscores, but no
+    /// radix prefix. If no radix works, the error of the last one is
+    /// returned. A radix outside 2 to 36, or an empty list, is an
+    /// `InvalidRadix` error.
+    ///
+    /// # Arguments
+    /// - `text`: Digits of a number without a radix prefix.
+    /// - `radixes`: Radixes to try, in order.
+    /// # Returns
+    /// - Numerical result or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::{NumLiteralTrait, ParseLiteralError};
+    ///
+    /// assert_eq!(u32::parse_literal_try_radixes("10", &[16, 10]), Ok(16));
+    /// assert_eq!(u32::parse_literal_try_radixes("10", &[10, 16]), Ok(10));
+    /// assert_eq!(u32::parse_literal_try_radixes("FF", &[10, 16]), Ok(255));
+    /// assert_eq!(u32::parse_literal_try_radixes("FG", &[10, 16]), Err(ParseLiteralError::InvalidDigit));
+    /// ```
+    fn parse_literal_try_radixes(text: impl AsRef<str>, radixes: &[u32]) -> Result<T, ParseLiteralError>;
+
+    /// Convert a literal to a number value and report how it was
+    /// written, or return an error.
+    ///
+    /// Besides the value, the details hold the detected format, the radix
+    /// prefix exactly as written and the written sign, so tools rewriting
+    /// literals can keep the author's casing and an explicit `+`.
+    ///
+    /// # Arguments
+    /// - `text`: Textual representation of a number.
+    /// # Returns
+    /// - Numerical result with its format, prefix and sign, or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::{LiteralFormat, LiteralSign, NumLiteralTrait};
+    ///
+    /// let details = u32::parse_literal_details("+0XFF").unwrap();
+    /// assert_eq!(details.value, 255);
+    /// assert_eq!(details.format, LiteralFormat::Hexadecimal);
+    /// assert_eq!(details.prefix, "0X");
+    /// assert_eq!(details.sign, LiteralSign::ExplicitPlus);
+    /// ```
+    fn parse_literal_details(text: &str) -> Result<LiteralDetails<'_, T>, ParseLiteralError>;
+
+    /// Convert the number at the start of a text to a number value and
+    /// return the unit written after it, or return an error.
+    ///
+    /// The number is either an integer literal as accepted by
+    /// `parse_literal` or a decimal float as accepted by
+    /// `parse_float_literal`. The unit is returned as written, without
+    /// surrounding whitespace, and is empty if the text holds only a
+    /// number.
+    ///
+    /// # Arguments
+    /// - `text`: Number followed by an optional unit.
+    /// # Returns
+    /// - Numerical result and unit, or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::NumLiteralTrait;
+    ///
+    /// let result = u32::parse_value_unit("100ms");
+    /// assert_eq!(result, Ok((100, "ms")));
+    ///
+    /// let result = f64::parse_value_unit("3.3 V");
+    /// assert_eq!(result, Ok((3.3, "V")));
+    ///
+    /// let result = u32::parse_value_unit("0x200");
+    /// assert_eq!(result, Ok((512, "")));
+    /// ```
+    fn parse_value_unit(text: &str) -> Result<(T, &str), ParseLiteralError>;
+
+    /// Convert a hexadecimal or binary literal whose bytes are written in
+    /// little-endian order, as copied from a memory dump, or return an
+    /// error.
+    ///
+    /// The digits must form whole bytes, an even number of hex digits or
+    /// a multiple of eight binary digits, otherwise `IncompleteByte` is
+    /// returned. Other formats return `UnsupportedFormat`.
+    ///
+    /// # Arguments
+    /// - `text`: Textual representation of a number.
+    /// # Returns
+    /// - Numerical result or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::{NumLiteralTrait, ParseLiteralError};
+    ///
+    /// let result = u32::parse_literal_le("0xEFBEADDE");
+    /// assert_eq!(result, Ok(0xDEADBEEF));
+    ///
+    /// let result = u16::parse_literal_le("0b00000001_10000000");
+    /// assert_eq!(result, Ok(0x8001));
+    ///
+    /// let result = u32::parse_literal_le("0xABC");
+    /// assert_eq!(result, Err(ParseLiteralError::IncompleteByte));
+    /// ```
+    fn parse_literal_le(text: impl AsRef<str>) -> Result<T, ParseLiteralError>;
+
     /// Determine the literal type, then convert to a number value or
     /// return the provided fallback if the parsing fails.
     ///
@@ -82,10 +353,157 @@ pub trait NumLiteralTrait<T: Num>: Num {
     /// assert_eq!(result, 0xfabc);
     ///    
     /// ```
-    fn parse_literal_fallback(text: &str, fallback: T) -> T;
+    fn parse_literal_fallback(text: impl AsRef<str>, fallback: T) -> T;
+
+    /// Determine the literal type, then convert to a number value,
+    /// clamped to the range of the type, or return an error.
+    ///
+    /// A value beyond the range of `T` yields `T::max_value()`, or
+    /// `T::min_value()` for negative values. Other errors, like invalid
+    /// digits or a `-` for an unsigned type, are still reported.
+    ///
+    /// # Arguments
+    /// - `text`: Textual representation of a number.
+    /// # Returns
+    /// - Numerical result or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::{NumLiteralTrait, ParseLiteralError};
+    ///
+    /// assert_eq!(u8::parse_literal_saturating("0x1FF"), Ok(255));
+    /// assert_eq!(i8::parse_literal_saturating("-300"), Ok(-128));
+    /// assert_eq!(u8::parse_literal_saturating("0xFG"), Err(ParseLiteralError::InvalidDigit));
+    /// ```
+    fn parse_literal_saturating(text: impl AsRef<str>) -> Result<T, ParseLiteralError> where T: Bounded;
+
+    /// Convert a decimal floating-point literal to a number value or
+    /// return an error.
+    ///
+    /// # Arguments
+    /// - `text`: Textual representation of a number.
+    /// # Returns
+    /// - Numerical result or error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_traits::Num;
+    /// use num_literal_traits::NumLiteralTrait;
+    ///
+    /// let result = f64::parse_float_literal("1.50");
+    /// assert_eq!(result, Ok(1.5));
+    ///
+    /// let result = f64::parse_float_literal("-2.5e3");
+    /// assert_eq!(result, Ok(-2500.0));
+    ///
+    /// let result = f32::parse_float_literal("1_000.25");
+    /// assert_eq!(result, Ok(1000.25));
+    /// ```
+    ///
+    /// # Supported formats
+    ///
+    /// Decimal     : `1.5`, `-0.25`, `1000`
+    /// Scientific  : `1.5e3`, `2E-4`, `-3e+2`
+    ///
+    /// The sign is passed on to the number type, so `"-0.0"` yields a
+    /// negative zero for floats. Underscores `_` are removed before
+    /// converting. Types whose `from_str_radix` understands a decimal
+    /// point but no exponent, like `rust_decimal::Decimal`, get scientific
+    /// notation rewritten into positional notation first.
+    ///
+    /// As in C and Rust, the digits before or after the decimal point may
+    /// be left out, so `"5."` is 5.0 and `"-.5"` is -0.5 for any number
+    /// type, while a point without any digits, like `"."`, is an
+    /// `InvalidDigit` error.
+    ///
+    /// Only finite numbers in these formats are accepted. The special
+    /// values `inf`, `infinity` and `NaN`, which `f32` and `f64` read in
+    /// `from_str_radix`, are an `InvalidDigit` error, for every number
+    /// type. A finite number too large for a float still becomes infinity.
+    fn parse_float_literal(text: impl AsRef<str>) -> Result<T, ParseLiteralError>;
 }
 
+mod ada;
+mod bits;
+mod bytes;
+mod constant;
+mod crockford;
+mod details;
+mod duration;
+mod error;
+mod expr;
+mod format;
+mod formatting;
+mod grouped;
+mod key_value;
+mod list;
+mod literal;
+mod normalize;
+mod overpunch;
 mod parse_literal;
+mod parser;
+mod range;
+mod report;
+mod rkm;
+mod roman;
+mod saturating;
+mod scaled;
+mod scan;
+mod target;
+mod tokenize;
+mod warning;
+mod words;
+#[cfg(feature = "half")]
+mod float16;
+
+pub use ada::parse_ada_literal;
+pub use bits::{
+    parse_f32_from_bits_literal, parse_f64_from_bits_literal, parse_gray_literal, parse_ones_complement,
+    parse_reinterpret_bits, parse_sign_magnitude, parse_width_literal,
+};
+pub use bytes::{parse_checked_hex, parse_colon_hex, parse_to_bytes, sum_checksum, xor_checksum, Endian};
+pub use constant::parse_in_radix;
+pub use crockford::{parse_crockford_base32, parse_crockford_base32_checked};
+pub use details::{LiteralDetails, LiteralSign};
+pub use duration::{iso8601_numeric_components, parse_duration_literal};
+pub use error::ParseLiteralError;
+pub use expr::{eval_literal_expr, parse_power_literal, parse_times_ten_literal};
+pub use format::{detect_format, LiteralFormat};
+pub use formatting::{
+    format_literal, format_literal_with, to_binary_literal, to_hex_literal, to_octal_literal,
+    FormatOptions, LiteralInteger,
+};
+pub use grouped::{parse_accounting_literal, parse_grouped_literal, parse_grouped_literal_auto};
+pub use key_value::parse_kv_literal;
+pub use list::{parse_literal_array, parse_literal_partition, parse_literals_lossy, parse_nth_literal};
+pub use literal::{Literal, LiteralValue};
+pub use normalize::{normalize_literals, normalize_literals_to};
+pub use overpunch::parse_overpunch;
+pub use parser::LiteralParser;
+pub use range::parse_range_literal;
+pub use report::{analyze_literal, LiteralReport};
+pub use rkm::parse_rkm;
+pub use roman::parse_roman;
+pub use saturating::parse_saturating_literal;
+pub use scaled::{parse_basis_points, parse_scaled_literal, parse_scaled_value_unit, parse_with_scale_table};
+pub use target::{parse_literal_into, LiteralTarget};
+pub use tokenize::{extract_first_literal, literals, Literals};
+pub use warning::{literal_warnings, parse_literal_checked, LiteralWarning};
+pub use words::parse_words;
+#[cfg(feature = "half")]
+pub use float16::parse_f16_literal;
+#[cfg(feature = "macros")]
+pub use num_literal_traits_macros::parse_literal;
+
+/// Items used by the expansion of `parse_literal!` and
+/// `literal_consts!`, not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::constant::{assert_integer, parse_int_literal, unwrap_int_literal, PrimitiveInteger};
+}
 
 #[cfg(test)]
 mod tests {    
@@ -109,6 +527,19 @@ mod tests {
         assert_eq!(result, Ok(239522));    
     }
 
+    #[test]
+    fn octal_prefix_works() {
+        let result = u32::parse_literal("0o723642");
+        assert_eq!(result, Ok(239522));
+    }
+
+    #[test]
+    fn base36_works() {
+        assert_eq!(u32::parse_literal("0zZ9"), Ok(35 * 36 + 9));
+        assert_eq!(u32::parse_literal("0Zwxyz"), Ok(1_537_019));
+        assert_eq!(u32::parse_literal("0zWxYz"), Ok(1_537_019));
+    }
+
     #[test]
     fn decimal_works() {
         let result = u32::parse_literal("9823642");
@@ -157,4 +588,543 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn overflow_fails() {
+        let res = u8::parse_literal("256");
+        assert_eq!(res, Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn empty_fails() {
+        let res = u32::parse_literal("0x");
+        assert_eq!(res, Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn u128_max_works() {
+        let result = u128::parse_literal("0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF");
+        assert_eq!(result, Ok(u128::MAX));
+
+        let result = u128::parse_literal("340282366920938463463374607431768211455");
+        assert_eq!(result, Ok(u128::MAX));
+    }
+
+    #[test]
+    fn u128_overflow_fails() {
+        let res = u128::parse_literal("0x1_0000_0000_0000_0000_0000_0000_0000_0000");
+        assert_eq!(res, Err(ParseLiteralError::Overflow));
+
+        let res = u128::parse_literal("340282366920938463463374607431768211456");
+        assert_eq!(res, Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn i128_limits_work() {
+        let result = i128::parse_literal("0x7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF");
+        assert_eq!(result, Ok(i128::MAX));
+
+        let result = i128::parse_literal("-170141183460469231731687303715884105728");
+        assert_eq!(result, Ok(i128::MIN));
+    }
+
+    #[test]
+    fn i128_overflow_fails() {
+        let res = i128::parse_literal("0x8000_0000_0000_0000_0000_0000_0000_0000");
+        assert_eq!(res, Err(ParseLiteralError::Overflow));
+
+        let res = i128::parse_literal("-170141183460469231731687303715884105729");
+        assert_eq!(res, Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn usize_isize_limits_work() {
+        assert_eq!(usize::parse_literal(format!("{:#x}", usize::MAX)), Ok(usize::MAX));
+        assert_eq!(usize::parse_literal(usize::MAX.to_string()), Ok(usize::MAX));
+        assert_eq!(isize::parse_literal(isize::MIN.to_string()), Ok(isize::MIN));
+        assert_eq!(isize::parse_literal(format!("{:#b}", isize::MAX)), Ok(isize::MAX));
+        assert_eq!(usize::parse_literal("-1"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn usize_isize_64_bit_overflow_fails() {
+        assert_eq!(usize::parse_literal("0xFFFF_FFFF_FFFF_FFFF"), Ok(u64::MAX as usize));
+        assert_eq!(usize::parse_literal("0x1_0000_0000_0000_0000"), Err(ParseLiteralError::Overflow));
+        assert_eq!(usize::parse_literal("18446744073709551616"), Err(ParseLiteralError::Overflow));
+        assert_eq!(isize::parse_literal("0x8000_0000_0000_0000"), Err(ParseLiteralError::Overflow));
+        assert_eq!(isize::parse_literal("-9223372036854775809"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn usize_isize_32_bit_overflow_fails() {
+        assert_eq!(usize::parse_literal("0xFFFF_FFFF"), Ok(u32::MAX as usize));
+        assert_eq!(usize::parse_literal("0x1_0000_0000"), Err(ParseLiteralError::Overflow));
+        assert_eq!(usize::parse_literal("4294967296"), Err(ParseLiteralError::Overflow));
+        assert_eq!(isize::parse_literal("0x8000_0000"), Err(ParseLiteralError::Overflow));
+        assert_eq!(isize::parse_literal("-2147483649"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn prefix_works() {
+        let result = u32::parse_literal_prefix("0xFF+3");
+        assert_eq!(result, Ok((255, 4)));
+
+        let result = u32::parse_literal_prefix("123 abc");
+        assert_eq!(result, Ok((123, 3)));
+    }
+
+    #[test]
+    fn prefix_with_suffix_works() {
+        assert_eq!(u32::parse_literal_prefix("0xFFu8+3"), Ok((255, 6)));
+        assert_eq!(u32::parse_literal_prefix("0u16 "), Ok((0, 4)));
+        assert_eq!(u32::parse_literal_prefix("12u8x"), Ok((12, 2)));
+        assert_eq!(u32::parse_literal_prefix("0zu8"), Ok((36 * 30 + 8, 4)));
+        assert_eq!(u32::parse_literal_rest("0xFFu8, 1"), Ok((255, ", 1")));
+        assert_eq!(u32::parse_literal_rest("0x100u8;"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn prefix_stops_at_invalid_digit() {
+        let result = u32::parse_literal_prefix("0b1012");
+        assert_eq!(result, Ok((5, 5)));
+
+        let result = u32::parse_literal_prefix("0777_8");
+        assert_eq!(result, Ok((511, 5)));
+    }
+
+    #[test]
+    fn prefix_without_digits_is_zero() {
+        let result = u32::parse_literal_prefix("0xyz");
+        assert_eq!(result, Ok((0, 1)));
+    }
+
+    #[test]
+    fn prefix_random_text_fails() {
+        let res = u32::parse_literal_prefix(" 12");
+        assert_eq!(res, Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn rest_works() {
+        assert_eq!(u32::parse_literal_rest("0xFF rest"), Ok((255, " rest")));
+        assert_eq!(u32::parse_literal_rest("0b1012"), Ok((5, "2")));
+        assert_eq!(u32::parse_literal_rest("'A','B'"), Ok((65, ",'B'")));
+        assert_eq!(u32::parse_literal_rest("42"), Ok((42, "")));
+    }
+
+    #[test]
+    fn rest_random_text_fails() {
+        assert_eq!(u32::parse_literal_rest("x + 1"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u32::parse_literal_rest(""), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn all_works() {
+        assert_eq!(u32::parse_literal_all("0xFF"), Ok(255));
+        assert_eq!(i32::parse_literal_all("  -0b11\t"), Ok(-3));
+        assert_eq!(u32::parse_literal_all("'A'"), Ok(65));
+        assert_eq!(u32::parse_literal_all(" 0xFFu8 "), Ok(255));
+        assert_eq!(i32::parse_literal_all("-0b11_i8"), Ok(-3));
+        assert_eq!(u32::parse_literal_all("0xFFu8x"), Err(ParseLiteralError::TrailingCharacters { offset: 4 }));
+    }
+
+    #[test]
+    fn all_trailing_fails() {
+        let res = u32::parse_literal_all("0xFF junk");
+        assert_eq!(res, Err(ParseLiteralError::TrailingCharacters { offset: 5 }));
+
+        let res = u32::parse_literal_all("  12+3");
+        assert_eq!(res, Err(ParseLiteralError::TrailingCharacters { offset: 4 }));
+
+        let res = u32::parse_literal_all("0b102");
+        assert_eq!(res, Err(ParseLiteralError::TrailingCharacters { offset: 4 }));
+    }
+
+    #[test]
+    fn all_invalid_fails() {
+        assert_eq!(u32::parse_literal_all("junk"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u32::parse_literal_all(""), Err(ParseLiteralError::Empty));
+        assert_eq!(u32::parse_literal_all("-"), Err(ParseLiteralError::LoneSign));
+    }
+
+    #[test]
+    fn details_prefix_works() {
+        let cases = [
+            ("0XFF", LiteralFormat::Hexadecimal, "0X"),
+            ("0xff", LiteralFormat::Hexadecimal, "0x"),
+            ("0B11", LiteralFormat::Binary, "0B"),
+            (" -0o17 ", LiteralFormat::Octal, "0o"),
+            ("017", LiteralFormat::Octal, "0"),
+            ("0Z1", LiteralFormat::Base36, "0Z"),
+            ("+15", LiteralFormat::Decimal, ""),
+            ("'A'", LiteralFormat::Char, ""),
+            ("0u8", LiteralFormat::Decimal, ""),
+            ("017i32", LiteralFormat::Octal, "0"),
+            ("0xFFu8", LiteralFormat::Hexadecimal, "0x"),
+            ("0zu8", LiteralFormat::Base36, "0z"),
+        ];
+        for (text, format, prefix) in cases {
+            let details = i32::parse_literal_details(text).unwrap();
+            assert_eq!((details.format, details.prefix), (format, prefix), "{text}");
+        }
+    }
+
+    #[test]
+    fn details_sign_works() {
+        let cases = [
+            ("5", LiteralSign::Implicit),
+            ("+5", LiteralSign::ExplicitPlus),
+            ("-5", LiteralSign::Minus),
+            (" +0x5 ", LiteralSign::ExplicitPlus),
+            ("-0b101", LiteralSign::Minus),
+            ("'5'", LiteralSign::Implicit),
+        ];
+        for (text, sign) in cases {
+            assert_eq!(i32::parse_literal_details(text).map(|details| details.sign), Ok(sign), "{text:?}");
+        }
+        assert_eq!(u32::parse_literal_details("-5").map(|details| details.sign), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn details_value_works() {
+        assert_eq!(i32::parse_literal_details("-0x10").map(|details| details.value), Ok(-16));
+        assert_eq!(u8::parse_literal_details("0x100").map(|details| details.value), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn h_prefix_works() {
+        assert_eq!(u32::parse_literal("0hCAFE"), Ok(0xCAFE));
+        assert_eq!(u32::parse_literal("0HcafE"), Ok(0xCAFE));
+        assert_eq!(i32::parse_literal("-0h1_0"), Ok(-16));
+        assert_eq!(u32::parse_literal_prefix("0hFF;"), Ok((255, 4)));
+    }
+
+    #[test]
+    fn h_prefix_bare_fails() {
+        assert_eq!(u32::parse_literal("0h"), Err(ParseLiteralError::Empty));
+        assert_eq!(u32::parse_literal("0H"), Err(ParseLiteralError::Empty));
+        assert_eq!(u32::parse_literal("0hG"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn str_input_works() {
+        let text: &str = "0xCAFE";
+        assert_eq!(u32::parse_literal(text), Ok(0xCAFE));
+        assert_eq!(u32::parse_literal_all(text), Ok(0xCAFE));
+        assert_eq!(u32::parse_value_unit("0xCAFE ms"), Ok((0xCAFE, "ms")));
+    }
+
+    #[test]
+    fn string_input_works() {
+        let text = String::from("0b1010");
+        assert_eq!(u32::parse_literal(&text), Ok(10));
+        assert_eq!(u32::parse_literal(text.clone()), Ok(10));
+        assert_eq!(u32::parse_literal_prefix(&text), Ok((10, 6)));
+        assert_eq!(u32::parse_literal_fallback(&text, 0), 10);
+        assert_eq!(u32::parse_literal_details(&text).map(|details| details.value), Ok(10));
+    }
+
+    #[test]
+    fn cow_input_works() {
+        use std::borrow::Cow;
+        let borrowed: Cow<str> = Cow::Borrowed("0o17");
+        let owned: Cow<str> = Cow::Owned(String::from("-0x10"));
+        assert_eq!(u32::parse_literal(&borrowed), Ok(15));
+        assert_eq!(i32::parse_literal(&owned), Ok(-16));
+        assert_eq!(u8::parse_literal_saturating(owned), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(f64::parse_float_literal(Cow::Borrowed("1.5e1")), Ok(15.0));
+    }
+
+    #[test]
+    fn worded_works() {
+        assert_eq!(u32::parse_literal_worded("bin 1010"), Ok(10));
+        assert_eq!(u32::parse_literal_worded("oct 777"), Ok(511));
+        assert_eq!(u32::parse_literal_worded("dec 42"), Ok(42));
+        assert_eq!(u32::parse_literal_worded("hex FF"), Ok(255));
+        assert_eq!(u32::parse_literal_worded(" Hex\tdead_BEEF "), Ok(0xDEAD_BEEF));
+        assert_eq!(i32::parse_literal_worded("hex -10"), Ok(-16));
+    }
+
+    #[test]
+    fn worded_invalid_fails() {
+        assert_eq!(u32::parse_literal_worded("bin 102"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u32::parse_literal_worded("hex 0xFF"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u8::parse_literal_worded("hex 100"), Err(ParseLiteralError::Overflow));
+        assert_eq!(u8::parse_literal_worded("hex -"), Err(ParseLiteralError::LoneSign));
+    }
+
+    #[test]
+    fn worded_unknown_word_falls_through() {
+        assert_eq!(u32::parse_literal_worded("0b11"), Ok(3));
+        assert_eq!(u32::parse_literal_worded("hex"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u32::parse_literal_worded("hexa FF"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u32::parse_literal_worded("hexFF"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn try_radixes_order_works() {
+        assert_eq!(u32::parse_literal_try_radixes("10", &[16, 10]), Ok(16));
+        assert_eq!(u32::parse_literal_try_radixes("10", &[10, 16]), Ok(10));
+        assert_eq!(u32::parse_literal_try_radixes("10", &[2, 10, 16]), Ok(2));
+        assert_eq!(u32::parse_literal_try_radixes("12", &[2, 10, 16]), Ok(12));
+        assert_eq!(u32::parse_literal_try_radixes(" c_afe ", &[10, 16]), Ok(0xCAFE));
+        assert_eq!(i32::parse_literal_try_radixes("-ff", &[10, 16]), Ok(-255));
+        assert_eq!(u8::parse_literal_try_radixes("100", &[10, 16]), Ok(100));
+        assert_eq!(u8::parse_literal_try_radixes("300", &[10, 16]), Err(ParseLiteralError::Overflow));
+        assert_eq!(u8::parse_literal_try_radixes("300", &[10, 4]), Ok(48));
+    }
+
+    #[test]
+    fn try_radixes_all_failing_fails() {
+        assert_eq!(u8::parse_literal_try_radixes("FFF", &[16, 10]), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u8::parse_literal_try_radixes("FFF", &[10, 16]), Err(ParseLiteralError::Overflow));
+        assert_eq!(u32::parse_literal_try_radixes("0x10", &[16, 10]), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u32::parse_literal_try_radixes("", &[16, 10]), Err(ParseLiteralError::Empty));
+        assert_eq!(u32::parse_literal_try_radixes("10", &[]), Err(ParseLiteralError::InvalidRadix));
+        assert_eq!(u32::parse_literal_try_radixes("10", &[10, 37]), Err(ParseLiteralError::InvalidRadix));
+        assert_eq!(u32::parse_literal_try_radixes("10", &[37, 10]), Err(ParseLiteralError::InvalidRadix));
+    }
+
+    #[test]
+    fn value_unit_works() {
+        let result = u32::parse_value_unit("100ms");
+        assert_eq!(result, Ok((100, "ms")));
+
+        let result = u64::parse_value_unit("512MB");
+        assert_eq!(result, Ok((512, "MB")));
+
+        let result = i32::parse_value_unit("-40 °C");
+        assert_eq!(result, Ok((-40, "°C")));
+
+        let result = u32::parse_value_unit("0xFFu8 px");
+        assert_eq!(result, Ok((255, "px")));
+    }
+
+    #[test]
+    fn value_unit_float_works() {
+        let result = f32::parse_value_unit("3.3V");
+        assert_eq!(result, Ok((3.3, "V")));
+
+        let result = f64::parse_value_unit("1.5em");
+        assert_eq!(result, Ok((1.5, "em")));
+
+        let result = f64::parse_value_unit("2e3Hz");
+        assert_eq!(result, Ok((2000.0, "Hz")));
+    }
+
+    #[test]
+    fn value_unit_without_unit_works() {
+        let result = u32::parse_value_unit(" 42 ");
+        assert_eq!(result, Ok((42, "")));
+    }
+
+    #[test]
+    fn value_unit_fails() {
+        let res = u32::parse_value_unit("ms");
+        assert_eq!(res, Err(ParseLiteralError::InvalidDigit));
+
+        let res = u32::parse_value_unit("3.3V");
+        assert_eq!(res, Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn little_endian_works() {
+        let result = u32::parse_literal_le("0xEFBEADDE");
+        assert_eq!(result, Ok(0xDEADBEEF));
+
+        let result = u64::parse_literal_le("0x0123_4567_89AB_CDEF");
+        assert_eq!(result, Ok(0xEFCD_AB89_6745_2301));
+    }
+
+    #[test]
+    fn little_endian_overflow_fails() {
+        let res = u16::parse_literal_le("0x000001");
+        assert_eq!(res, Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn little_endian_odd_digits_fails() {
+        let res = u64::parse_literal_le("0x1_2345_6789_ABCD_EF");
+        assert_eq!(res, Err(ParseLiteralError::IncompleteByte));
+
+        let res = u32::parse_literal_le("1234");
+        assert_eq!(res, Err(ParseLiteralError::UnsupportedFormat));
+    }
+
+    #[test]
+    fn signs_work() {
+        assert_eq!(i32::parse_literal("-0xFF"), Ok(-255));
+        assert_eq!(i32::parse_literal("+0b11"), Ok(3));
+        assert_eq!(i8::parse_literal("-0200"), Ok(-128));
+        assert_eq!(u32::parse_literal("+12"), Ok(12));
+    }
+
+    #[test]
+    fn lone_sign_fails() {
+        assert_eq!(i32::parse_literal("-"), Err(ParseLiteralError::LoneSign));
+        assert_eq!(i32::parse_literal(" + "), Err(ParseLiteralError::LoneSign));
+        assert_eq!(f64::parse_float_literal("-"), Err(ParseLiteralError::LoneSign));
+    }
+
+    #[test]
+    fn invalid_sign_fails() {
+        for text in ["--5", "+-5", "-+5", "++5", "- 5", "0x-5", "-0x+5"] {
+            assert_eq!(i32::parse_literal(text), Err(ParseLiteralError::InvalidSign), "{text}");
+        }
+        assert_eq!(f64::parse_float_literal("+-5.0"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn negative_unsigned_fails() {
+        assert_eq!(u32::parse_literal("-5"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(u8::parse_literal("-0x1"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn float_works() {
+        let result = f64::parse_float_literal("-1.25");
+        assert_eq!(result, Ok(-1.25));
+    }
+
+    #[test]
+    fn float_exponent_works() {
+        let result = f64::parse_float_literal("1.5e-3");
+        assert_eq!(result, Ok(0.0015));
+    }
+
+    #[test]
+    fn float_negative_zero_works() {
+        for text in ["-0.0", "-0", "-.0", "-0e5"] {
+            let result = f64::parse_float_literal(text).unwrap();
+            assert!(result == 0.0 && result.is_sign_negative(), "{text}");
+        }
+        assert!(f32::parse_float_literal("-0.0").unwrap().is_sign_negative());
+        assert!(f64::parse_float_literal("0.0").unwrap().is_sign_positive());
+        assert!(f64::parse_float_literal("+0.0").unwrap().is_sign_positive());
+    }
+
+    #[test]
+    fn negative_zero_keeps_sign() {
+        assert!(f64::parse_literal("-0x0").unwrap().is_sign_negative());
+        assert!(f64::parse_value_unit("-0.0V").unwrap().0.is_sign_negative());
+    }
+
+    #[test]
+    fn float_negative_unsigned_fails() {
+        assert_eq!(u32::parse_float_literal("-5"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(u32::parse_float_literal("-5e2"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(u8::parse_float_literal("-0"), u8::parse_literal("-0"));
+        assert_eq!(u32::parse_float_literal("-5"), u32::parse_literal("-5"));
+        assert_eq!(i8::parse_float_literal("-5e2"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn float_bare_point_works() {
+        assert_eq!(f64::parse_float_literal("5."), Ok(5.0));
+        assert_eq!(f64::parse_float_literal(".5"), Ok(0.5));
+        assert_eq!(f64::parse_float_literal("-.5"), Ok(-0.5));
+        assert_eq!(f64::parse_float_literal("+5."), Ok(5.0));
+        assert_eq!(f32::parse_float_literal(".5e1"), Ok(5.0));
+        assert_eq!(f32::parse_float_literal("5.e-1"), Ok(0.5));
+        assert_eq!(u32::parse_float_literal("5.e2"), Ok(500));
+        assert_eq!(f64::parse_value_unit(".5s"), Ok((0.5, "s")));
+    }
+
+    #[test]
+    fn float_bare_point_without_digits_fails() {
+        for text in [".", "-.", "+.", ".e1", "..5", "5..", " . "] {
+            assert_eq!(f64::parse_float_literal(text), Err(ParseLiteralError::InvalidDigit), "{text}");
+        }
+        assert_eq!(u32::parse_float_literal("5."), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn float_non_finite_fails() {
+        for text in ["inf", "-inf", "+Infinity", "NaN", "nan", " -NaN "] {
+            assert_eq!(f64::parse_float_literal(text), Err(ParseLiteralError::InvalidDigit), "{text}");
+            assert_eq!(f32::parse_float_literal(text), Err(ParseLiteralError::InvalidDigit), "{text}");
+        }
+        assert_eq!(f64::parse_float_literal("1e400"), Ok(f64::INFINITY));
+    }
+
+    #[test]
+    fn float_random_text_fails() {
+        let res = f64::parse_float_literal("1.5f");
+        assert!(res.is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_error_keeps_message() {
+        fn read_port(text: &str) -> std::io::Result<u16> {
+            Ok(u16::parse_literal_all(text)?)
+        }
+        let error = read_port("80 http").unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), ParseLiteralError::TrailingCharacters { offset: 3 }.to_string());
+        let error = std::io::Error::from(ParseLiteralError::Overflow);
+        assert_eq!(error.to_string(), ParseLiteralError::Overflow.to_string());
+    }
+
+    #[test]
+    fn boxed_error_keeps_message() {
+        fn parse_all(texts: &[&str]) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(texts.iter().map(u8::parse_literal).collect::<Result<_, _>>()?)
+        }
+        assert_eq!(parse_all(&["1", "0x2"]).unwrap(), [1, 2]);
+        let error = parse_all(&["1", "0x100"]).unwrap_err();
+        assert_eq!(error.to_string(), ParseLiteralError::Overflow.to_string());
+        assert_eq!(error.downcast_ref::<ParseLiteralError>(), Some(&ParseLiteralError::Overflow));
+        let error = std::thread::spawn(move || error.to_string()).join().unwrap();
+        assert_eq!(error, ParseLiteralError::Overflow.to_string());
+    }
+
+    #[cfg(feature = "decimal")]
+    mod decimal {
+        use super::*;
+        use rust_decimal::Decimal;
+
+        #[test]
+        fn decimal_hexadecimal_works() {
+            let result = Decimal::parse_literal("0x10");
+            assert_eq!(result, Ok(Decimal::from(16)));
+        }
+
+        #[test]
+        fn decimal_float_keeps_scale() {
+            let result = Decimal::parse_float_literal("1.50").unwrap();
+            assert_eq!(result, Decimal::new(150, 2));
+            assert_eq!(result.to_string(), "1.50");
+        }
+
+        #[test]
+        fn decimal_bare_point_works() {
+            assert_eq!(Decimal::parse_float_literal("5.").map(|value| value.to_string()), Ok("5".into()));
+            assert_eq!(Decimal::parse_float_literal("-.5"), Ok(Decimal::new(-5, 1)));
+        }
+
+        #[test]
+        fn decimal_non_finite_fails() {
+            for text in ["inf", "NaN", "-infinity"] {
+                assert_eq!(Decimal::parse_float_literal(text), Err(ParseLiteralError::InvalidDigit), "{text}");
+            }
+        }
+
+        #[test]
+        fn decimal_exponent_works() {
+            let result = Decimal::parse_float_literal("1.5e3");
+            assert_eq!(result, Ok(Decimal::from(1500)));
+
+            let result = Decimal::parse_float_literal("-25e-3");
+            assert_eq!(result, Ok(Decimal::new(-25, 3)));
+        }
+
+        #[test]
+        fn decimal_exponent_overflow_fails() {
+            let res = Decimal::parse_float_literal("1e40");
+            assert!(res.is_err());
+        }
+    }
+
 }
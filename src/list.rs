@@ -0,0 +1,235 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::{NumLiteralTrait, ParseLiteralError};
+
+/// Split a text into fields and convert each one as a literal, yielding
+/// `None` for fields that fail to parse.
+///
+/// Unlike a strict list parser, a bad field never stops the iteration,
+/// so the position of every field is kept. Fields are trimmed, and an
+/// empty field, like between `,,`, is `None`. An empty or blank text
+/// yields no fields at all.
+///
+/// # Arguments
+/// - `text`: Literals split by `sep`.
+/// - `sep`: Separator between the fields.
+/// # Returns
+/// - Numerical result per field, or `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_literals_lossy;
+///
+/// let values: Vec<Option<u8>> = parse_literals_lossy("1, 0x2, oops, 'A'", ',').collect();
+/// assert_eq!(values, [Some(1), Some(2), None, Some(65)]);
+/// ```
+pub fn parse_literals_lossy<T: Num>(text: &str, sep: char) -> impl Iterator<Item = Option<T>> + '_ {
+    (!text.trim().is_empty())
+        .then(|| text.split(sep))
+        .into_iter()
+        .flatten()
+        .map(|field| T::parse_literal(field).ok())
+}
+
+/// Split a text into exactly `N` fields and convert each one as a
+/// literal, or return an error.
+///
+/// This suits fixed-shape data like colors or vectors without allocating.
+/// Fields are trimmed, and an empty or blank text has no fields. A text
+/// with more or fewer than `N` fields is a `WrongCount` error, otherwise
+/// the first field that fails to parse gives the error.
+///
+/// # Arguments
+/// - `text`: Literals split by `sep`.
+/// - `sep`: Separator between the fields.
+/// # Returns
+/// - Array of numerical results or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_literal_array, ParseLiteralError};
+///
+/// assert_eq!(parse_literal_array::<u8, 3>("0xFF, 0x80, 0", ','), Ok([255, 128, 0]));
+/// assert_eq!(
+///     parse_literal_array::<u32, 4>("1 2 3", ' '),
+///     Err(ParseLiteralError::WrongCount { expected: 4, found: 3 }),
+/// );
+/// ```
+pub fn parse_literal_array<T: Num, const N: usize>(text: &str, sep: char) -> Result<[T; N], ParseLiteralError> {
+    let mut fields = (!text.trim().is_empty()).then(|| text.split(sep)).into_iter().flatten();
+    let mut found = 0;
+    let mut error = None;
+    let values: [Option<T>; N] = std::array::from_fn(|_| {
+        let field = fields.next()?;
+        found += 1;
+        match T::parse_literal(field) {
+            Ok(value) => Some(value),
+            Err(field_error) => {
+                error.get_or_insert(field_error);
+                None
+            },
+        }
+    });
+    found += fields.count();
+    if found != N {
+        return Err(ParseLiteralError::WrongCount { expected: N, found });
+    }
+    match error {
+        Some(error) => Err(error),
+        None => Ok(values.map(|value| value.unwrap())),
+    }
+}
+
+/// Split a text into fields and convert only the field at index `n` as a
+/// literal, or return an error.
+///
+/// The other fields are skipped without being parsed, and nothing is
+/// allocated. The field is trimmed, and a text with `n` or fewer fields
+/// is a `MissingField` error. An empty or blank text has no fields.
+///
+/// # Arguments
+/// - `text`: Literals split by `sep`.
+/// - `sep`: Separator between the fields.
+/// - `n`: Index of the field to convert, starting at 0.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_nth_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_nth_literal::<u8>("10, 0x20, 'A'", ',', 1), Ok(32));
+/// assert_eq!(parse_nth_literal::<u8>("10, 0x20, 'A'", ',', 3), Err(ParseLiteralError::MissingField));
+/// ```
+pub fn parse_nth_literal<T: Num>(text: &str, sep: char, n: usize) -> Result<T, ParseLiteralError> {
+    let field = (!text.trim().is_empty()).then(|| text.split(sep).nth(n)).flatten();
+    T::parse_literal(field.ok_or(ParseLiteralError::MissingField)?)
+}
+
+/// Convert many texts as literals, collecting the values that parse and
+/// the errors of those that do not.
+///
+/// Unlike collecting into a `Result<Vec<T>, _>`, one bad text does not
+/// discard the others. Each failure is reported with the position of its
+/// text in `texts`, so it can be traced back to its row.
+///
+/// # Arguments
+/// - `texts`: Textual representations of numbers.
+/// # Returns
+/// - The numerical results in input order, and the position and error
+///   of each text that failed.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_literal_partition, ParseLiteralError};
+///
+/// let (values, failures) = parse_literal_partition::<u8, _>(["1", "0x2", "oops", "300"]);
+/// assert_eq!(values, [1, 2]);
+/// assert_eq!(failures, [(2, ParseLiteralError::InvalidDigit), (3, ParseLiteralError::Overflow)]);
+/// ```
+pub fn parse_literal_partition<T, I>(texts: I) -> (Vec<T>, Vec<(usize, ParseLiteralError)>)
+where
+    T: Num,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut values = Vec::new();
+    let mut failures = Vec::new();
+    for (index, text) in texts.into_iter().enumerate() {
+        match T::parse_literal(text) {
+            Ok(value) => values.push(value),
+            Err(error) => failures.push((index, error)),
+        }
+    }
+    (values, failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lossy_bad_field_is_none() {
+        let values: Vec<Option<u8>> = parse_literals_lossy("0x10;0b11;0xZZ;300;7", ';').collect();
+        assert_eq!(values, [Some(16), Some(3), None, None, Some(7)]);
+    }
+
+    #[test]
+    fn lossy_empty_field_is_none() {
+        let values: Vec<Option<i32>> = parse_literals_lossy(",-1,,", ',').collect();
+        assert_eq!(values, [None, Some(-1), None, None]);
+    }
+
+    #[test]
+    fn nth_works() {
+        assert_eq!(parse_nth_literal::<u8>("10, 0x20, 'A'", ',', 0), Ok(10));
+        assert_eq!(parse_nth_literal::<u8>("10, 0x20, 'A'", ',', 2), Ok(65));
+        assert_eq!(parse_nth_literal::<u8>("oops;0b11;300", ';', 1), Ok(3));
+        assert_eq!(parse_nth_literal::<u8>("1,,3", ',', 1), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn nth_out_of_range_fails() {
+        assert_eq!(parse_nth_literal::<u8>("10, 0x20, 'A'", ',', 3), Err(ParseLiteralError::MissingField));
+        assert_eq!(parse_nth_literal::<u8>("", ',', 0), Err(ParseLiteralError::MissingField));
+        assert_eq!(parse_nth_literal::<u8>("1", ',', usize::MAX), Err(ParseLiteralError::MissingField));
+    }
+
+    #[test]
+    fn partition_mixed_works() {
+        let texts: Vec<String> = ["0x10", "", "-1", "'A'", "0b2"].map(String::from).into();
+        let (values, failures) = parse_literal_partition::<u8, _>(&texts);
+        assert_eq!(values, [16, 65]);
+        assert_eq!(failures, [
+            (1, ParseLiteralError::Empty),
+            (2, ParseLiteralError::InvalidSign),
+            (4, ParseLiteralError::InvalidDigit),
+        ]);
+    }
+
+    #[test]
+    fn partition_all_valid_works() {
+        let (values, failures) = parse_literal_partition::<i32, _>("1 -0x2 0o3".split(' '));
+        assert_eq!(values, [1, -2, 3]);
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn array_works() {
+        assert_eq!(parse_literal_array::<u32, 4>("1 2 3 4", ' '), Ok([1, 2, 3, 4]));
+        assert_eq!(parse_literal_array::<u8, 3>("0xFF, 0b1, 'A'", ','), Ok([255, 1, 65]));
+        assert_eq!(parse_literal_array::<f32, 0>("", ','), Ok([]));
+    }
+
+    #[test]
+    fn array_wrong_count_fails() {
+        let error = |expected, found| ParseLiteralError::WrongCount { expected, found };
+        assert_eq!(parse_literal_array::<u32, 4>("1 2 3", ' ').unwrap_err(), error(4, 3));
+        assert_eq!(parse_literal_array::<u32, 2>("1,2,3,4", ',').unwrap_err(), error(2, 4));
+        assert_eq!(parse_literal_array::<u32, 2>("1,x,3", ',').unwrap_err(), error(2, 3));
+        assert_eq!(parse_literal_array::<u32, 1>(" ", ',').unwrap_err(), error(1, 0));
+    }
+
+    #[test]
+    fn array_invalid_field_fails() {
+        assert_eq!(parse_literal_array::<u8, 3>("1,300,x", ','), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_literal_array::<u8, 3>("1,,3", ','), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn lossy_empty_text_is_empty() {
+        assert_eq!(parse_literals_lossy::<u8>("", ',').count(), 0);
+        assert_eq!(parse_literals_lossy::<u8>("  ", ',').count(), 0);
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::str::FromStr;
+use num_traits::Num;
+use crate::{format_literal_with, FormatOptions, LiteralFormat, LiteralInteger, NumLiteralTrait, ParseLiteralError};
+use crate::constant::strip_rust_suffix;
+
+/// A number parsed from a literal, for APIs that convert through
+/// `FromStr` or `TryFrom<&str>`, like `str::parse`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::Literal;
+///
+/// let Literal(value) = "0xCAFE".parse::<Literal<u32>>().unwrap();
+/// assert_eq!(value, 0xcafe);
+///
+/// let literal = Literal::<u32>::try_from("0b101").unwrap();
+/// assert_eq!(literal.into_inner(), 5);
+/// ```
+///
+/// `Display` writes the canonical decimal form of the number, not the
+/// format it was parsed from, so `"0xFF"` is displayed as `255`. For
+/// integers the output parses back into the same value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Literal<T>(pub T);
+
+impl<T> Literal<T> {
+    /// The parsed number.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Literal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Num> FromStr for Literal<T> {
+    type Err = ParseLiteralError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        T::parse_literal(text).map(Literal)
+    }
+}
+
+impl<T: Num> TryFrom<&str> for Literal<T> {
+    type Error = ParseLiteralError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        T::parse_literal(text).map(Literal)
+    }
+}
+
+/// A number parsed from a literal that remembers how it was written, so
+/// it can be written back in the same style after changing it.
+///
+/// `Display` writes the value with the original radix prefix, including
+/// its case, the original case of the digits, like `0XFF` or `0xff`, and
+/// a Rust type suffix like `u8`. Digit separators, leading zeros and a
+/// `+` sign are not kept, so the output is an equivalent literal rather
+/// than an identical one.
+/// A char literal whose value is no longer a printable ASCII character is
+/// written as decimal.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::LiteralValue;
+///
+/// let mut literal = LiteralValue::<u32>::parse("0XFF").unwrap();
+/// assert_eq!(literal.to_string(), "0XFF");
+///
+/// literal.value += 1;
+/// assert_eq!(literal.to_string(), "0X100");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LiteralValue<T> {
+    /// The parsed number.
+    pub value: T,
+    format: LiteralFormat,
+    prefix: String,
+    suffix: String,
+    uppercase: bool,
+}
+
+impl<T: Num> LiteralValue<T> {
+    /// Determine the literal type, then convert to a number value and
+    /// keep its style, or return an error.
+    ///
+    /// # Arguments
+    /// - `text`: Textual representation of a number.
+    /// # Returns
+    /// - Numerical result with its style or error.
+    pub fn parse(text: &str) -> Result<Self, ParseLiteralError> {
+        let details = T::parse_literal_details(text)?;
+        let text = text.trim();
+        let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+        let digits = &strip_rust_suffix(unsigned).0[details.prefix.len()..];
+        Ok(Self {
+            uppercase: details.format == LiteralFormat::Char || !digits.bytes().any(|b| b.is_ascii_lowercase()),
+            format: details.format,
+            prefix: details.prefix.to_string(),
+            suffix: unsigned[details.prefix.len() + digits.len()..].to_string(),
+            value: details.value,
+        })
+    }
+}
+
+impl<T> LiteralValue<T> {
+    /// The format the literal was written in.
+    pub fn format(&self) -> LiteralFormat {
+        self.format
+    }
+}
+
+impl<T: LiteralInteger> fmt::Display for LiteralValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = FormatOptions::new().prefix(false).uppercase(self.uppercase);
+        let literal = format_literal_with(self.value, self.format, options);
+        if self.format == LiteralFormat::Char {
+            return f.write_str(&literal);
+        }
+        match literal.strip_prefix('-') {
+            Some(digits) => write!(f, "-{}{digits}{}", self.prefix, self.suffix),
+            None => write!(f, "{}{literal}{}", self.prefix, self.suffix),
+        }
+    }
+}
+
+impl<T: Num> FromStr for LiteralValue<T> {
+    type Err = ParseLiteralError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_works() {
+        assert_eq!("0o17".parse::<Literal<u8>>(), Ok(Literal(15)));
+        assert_eq!("0x100".parse::<Literal<u8>>(), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn try_from_works() {
+        assert_eq!(Literal::<u32>::try_from("0b101"), Ok(Literal(5)));
+        assert_eq!(Literal::<u32>::try_from("0b102"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn display_is_decimal() {
+        let literal: Literal<i32> = "-0xFF".parse().unwrap();
+        assert_eq!(literal.to_string(), "-255");
+        assert_eq!(format!("{literal:>6}"), "  -255");
+    }
+
+    #[test]
+    fn display_round_trip_works() {
+        for text in ["0", "0b1010", "0o777", "0xDEAD_BEEF", "'A'", "-0zZZ", "+42"] {
+            let literal: Literal<i64> = text.parse().unwrap();
+            assert_eq!(literal.to_string().parse(), Ok(literal), "{text}");
+        }
+    }
+
+    #[test]
+    fn value_round_trip_works() {
+        let texts = [
+            "0", "123", "-42", "0b101", "0B101", "0o17", "0O17", "017", "00", "0xff", "0XFF", "0xFF", "0Xab",
+            "-0x1f", "0hFF", "0Hff", "0zZ9", "0Zz9", "'A'", "' '", "0xFFu8", "0Xffi32", "0b1u8", "-0o17i64",
+            "42usize", "0u8", "0zu8",
+        ];
+        for text in texts {
+            let literal = LiteralValue::<i64>::parse(text).unwrap();
+            assert_eq!(literal.to_string(), text);
+            assert_eq!(LiteralValue::parse(&literal.to_string()), Ok(literal));
+        }
+    }
+
+    #[test]
+    fn value_equivalent_round_trip_works() {
+        for (text, written) in [("+0x10", "0x10"), ("0xDE_AD", "0xDEAD"), ("0x00ff", "0xff"), (" 1_000 ", "1000")] {
+            assert_eq!(LiteralValue::<i64>::parse(text).unwrap().to_string(), written);
+        }
+    }
+
+    #[test]
+    fn value_modified_keeps_style() {
+        let mut literal: LiteralValue<i32> = "0Xab".parse().unwrap();
+        literal.value = -0xCD;
+        assert_eq!(literal.to_string(), "-0Xcd");
+        assert_eq!(literal.format(), LiteralFormat::Hexadecimal);
+
+        let mut literal: LiteralValue<u8> = "'A'".parse().unwrap();
+        literal.value += 1;
+        assert_eq!(literal.to_string(), "'B'");
+        literal.value = 0;
+        assert_eq!(literal.to_string(), "0");
+
+        let mut literal: LiteralValue<u32> = "0xabu16".parse().unwrap();
+        literal.value = 0xCDEF;
+        assert_eq!(literal.to_string(), "0xcdefu16");
+    }
+
+    #[test]
+    fn value_invalid_fails() {
+        assert_eq!(LiteralValue::<u8>::parse("0x100"), Err(ParseLiteralError::Overflow));
+        assert_eq!("CAFE".parse::<LiteralValue<u32>>(), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn try_from_with_question_mark_works() {
+        fn sum(texts: &[&str]) -> Result<i64, ParseLiteralError> {
+            let mut sum = 0;
+            for &text in texts {
+                sum += Literal::<i64>::try_from(text)?.into_inner();
+            }
+            Ok(sum)
+        }
+        assert_eq!(sum(&["0x10", "-'A'", "010"]), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(sum(&["0x10", "'A'", "-010"]), Ok(16 + 65 - 8));
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{format_literal, literals, LiteralFormat, NumLiteralTrait};
+use crate::constant::strip_rust_suffix;
+use crate::formatting::char_literal;
+
+fn format_value(value: u128, format: LiteralFormat) -> Option<String> {
+    match format {
+        LiteralFormat::Char => char_literal(value),
+        _ => Some(format_literal(value, format)),
+    }
+}
+
+/// Rewrite every numeric literal in a text into decimal.
+///
+/// # Arguments
+/// - `text`: Text containing literals, like an expression.
+/// # Returns
+/// - The text with each literal replaced by its decimal form.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::normalize_literals;
+///
+/// let result = normalize_literals("mask = 0xFF | 0b10");
+/// assert_eq!(result, "mask = 255 | 2");
+/// ```
+pub fn normalize_literals(text: &str) -> String {
+    normalize_literals_to(text, LiteralFormat::Decimal)
+}
+
+/// Rewrite every numeric literal in a text into the given format.
+///
+/// Literals are found with [`literals`](crate::literals), all text in
+/// between is copied unchanged. A Rust type suffix like `u8` is kept after
+/// the rewritten literal. Literals too large for `u128`, and values
+/// without a printable ASCII character or with a suffix when targeting
+/// `Char`, are kept as written.
+///
+/// # Arguments
+/// - `text`: Text containing literals, like an expression.
+/// - `format`: Format to rewrite the literals into.
+/// # Returns
+/// - The text with each literal replaced by its rewritten form.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{normalize_literals_to, LiteralFormat};
+///
+/// let result = normalize_literals_to("x = 255 + 'A'", LiteralFormat::Hexadecimal);
+/// assert_eq!(result, "x = 0xFF + 0x41");
+/// ```
+pub fn normalize_literals_to(text: &str, format: LiteralFormat) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0;
+    for (range, _) in literals(text) {
+        let literal = &text[range.clone()];
+        let suffix = &literal[strip_rust_suffix(literal).0.len()..];
+        let formatted = u128::parse_literal(literal).ok()
+            .filter(|_| suffix.is_empty() || format != LiteralFormat::Char)
+            .and_then(|value| format_value(value, format))
+            .map(|formatted| formatted + suffix);
+        let Some(formatted) = formatted else {
+            continue;
+        };
+        result.push_str(&text[copied..range.start]);
+        result.push_str(&formatted);
+        copied = range.end;
+    }
+    result.push_str(&text[copied..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_round_trip_works() {
+        let text = "flags = (0x1F & 0b0110) | 017 | 'a';";
+        let hex = normalize_literals_to(text, LiteralFormat::Hexadecimal);
+        assert_eq!(hex, "flags = (0x1F & 0x6) | 0xF | 0x61;");
+
+        let decimal = normalize_literals(&hex);
+        assert_eq!(decimal, "flags = (31 & 6) | 15 | 97;");
+        assert_eq!(normalize_literals(text), decimal);
+    }
+
+    #[test]
+    fn normalize_keeps_other_text() {
+        let text = "cafe = x1 + y_0; // état 1.5";
+        assert_eq!(normalize_literals_to(text, LiteralFormat::Binary), text);
+    }
+
+    #[test]
+    fn normalize_keeps_unrepresentable() {
+        let text = "0x1 0x1_0000_0000_0000_0000_0000_0000_0000_0000 200";
+        let result = normalize_literals_to(text, LiteralFormat::Char);
+        assert_eq!(result, "0x1 0x1_0000_0000_0000_0000_0000_0000_0000_0000 200");
+
+        let result = normalize_literals_to("77 0", LiteralFormat::Octal);
+        assert_eq!(result, "0115 0");
+    }
+
+    #[test]
+    fn normalize_keeps_suffix() {
+        assert_eq!(normalize_literals("a 0xFFu8 b 12 0zu8"), "a 255u8 b 12 1088");
+        assert_eq!(normalize_literals_to("0x41u8 0x41", LiteralFormat::Char), "0x41u8 'A'");
+        assert_eq!(normalize_literals_to("-1i8", LiteralFormat::Binary), "-0b1i8");
+    }
+}
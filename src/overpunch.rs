@@ -0,0 +1,114 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::ParseLiteralError;
+use crate::parse_literal::from_digits;
+
+/// Overpunch characters with the digit and whether they are negative, as
+/// used in EBCDIC-derived zoned decimals.
+const OVERPUNCH: [(char, char, bool); 20] = [
+    ('{', '0', false),
+    ('A', '1', false),
+    ('B', '2', false),
+    ('C', '3', false),
+    ('D', '4', false),
+    ('E', '5', false),
+    ('F', '6', false),
+    ('G', '7', false),
+    ('H', '8', false),
+    ('I', '9', false),
+    ('}', '0', true),
+    ('J', '1', true),
+    ('K', '2', true),
+    ('L', '3', true),
+    ('M', '4', true),
+    ('N', '5', true),
+    ('O', '6', true),
+    ('P', '7', true),
+    ('Q', '8', true),
+    ('R', '9', true),
+];
+
+/// Convert a COBOL zoned decimal with an overpunched sign, like `12J`
+/// for -121, to a number value or return an error.
+///
+/// The last character carries both the last digit and the sign: `{` and
+/// `A`-`I` are the positive digits 0-9, `}` and `J`-`R` the negative
+/// ones. A plain digit in its place makes an unsigned, positive number.
+/// All other characters must be decimal digits, and an unknown
+/// overpunch character is an `InvalidDigit` error.
+///
+/// # Arguments
+/// - `text`: Zoned decimal digits with an overpunched last character.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_overpunch;
+///
+/// assert_eq!(parse_overpunch::<i32>("12J"), Ok(-121));
+/// assert_eq!(parse_overpunch::<i32>("12{"), Ok(120));
+/// assert_eq!(parse_overpunch::<i32>("123"), Ok(123));
+/// ```
+pub fn parse_overpunch<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let text = text.trim();
+    let Some(last) = text.chars().next_back() else {
+        return Err(ParseLiteralError::Empty);
+    };
+    let (digit, negative) = match OVERPUNCH.into_iter().find(|&(overpunch, _, _)| overpunch == last) {
+        Some((_, digit, negative)) => (digit, negative),
+        None if last.is_ascii_digit() => (last, false),
+        None => return Err(ParseLiteralError::InvalidDigit),
+    };
+    let digits = &text[..text.len() - last.len_utf8()];
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ParseLiteralError::InvalidDigit);
+    }
+    from_digits(negative, &format!("{digits}{digit}"), 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overpunch_positive_works() {
+        assert_eq!(parse_overpunch::<i32>("12{"), Ok(120));
+        assert_eq!(parse_overpunch::<i32>("12A"), Ok(121));
+        assert_eq!(parse_overpunch::<i32>("00I"), Ok(9));
+        assert_eq!(parse_overpunch::<u32>("4E"), Ok(45));
+    }
+
+    #[test]
+    fn overpunch_negative_works() {
+        assert_eq!(parse_overpunch::<i32>("J"), Ok(-1));
+        assert_eq!(parse_overpunch::<i32>("12J"), Ok(-121));
+        assert_eq!(parse_overpunch::<i32>("12}"), Ok(-120));
+        assert_eq!(parse_overpunch::<i32>("99R"), Ok(-999));
+        assert_eq!(parse_overpunch::<i8>("12H"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_overpunch::<i8>("12Q"), Ok(-128));
+    }
+
+    #[test]
+    fn overpunch_unsigned_works() {
+        assert_eq!(parse_overpunch::<u32>("123"), Ok(123));
+        assert_eq!(parse_overpunch::<u32>("12J"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn overpunch_malformed_fails() {
+        assert_eq!(parse_overpunch::<i32>(""), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_overpunch::<i32>("12S"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_overpunch::<i32>("1A2J"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_overpunch::<i32>("-12J"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_overpunch::<i32>("12j"), Err(ParseLiteralError::InvalidDigit));
+    }
+}
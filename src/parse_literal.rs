@@ -6,36 +6,408 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use num_traits::Num;
-use crate::NumLiteralTrait;
+use num_traits::{Bounded, Num};
+use crate::{
+    detect_format, LiteralDetails, LiteralFormat, LiteralParser, LiteralSign, NumLiteralTrait, ParseLiteralError,
+};
+use crate::constant::strip_rust_suffix;
+use crate::format::{match_prefix, RUST_SUFFIXES};
+use crate::scan::{read_sign, with_digits, with_formatted};
 
-fn identify_literal<'a>(text: &'a str) -> (&'a str, u32) {
-    let text = text.trim();    
-    return
-        if text.to_lowercase().starts_with("0b") { (&text[2..], 2) } else            
-        if text.to_lowercase().starts_with("0x") { (&text[2..], 16) } else            
-        if text.len() > 1 && text.to_lowercase().starts_with("0") { (&text[1..], 8) } else
-        { (text, 10) };
+/// Exponents beyond this magnitude are not expanded textually, to keep
+/// pathological inputs like `1e999999999` from allocating huge strings.
+const MAX_SHIFTED_EXPONENT: usize = 4096;
+
+pub(crate) fn identify_literal(text: &str) -> (&str, u32) {
+    let text = text.trim();
+    if let Some((prefix, format)) = match_prefix(text) {
+        return (&text[prefix.len()..], format.radix().unwrap_or(10));
+    }
+    match detect_format(text) {
+        LiteralFormat::Octal => (&text[1..], 8),
+        _ => (text, 10),
+    }
+}
+
+/// Length in bytes of the literal at the start of `text`, which ends at
+/// the first character that cannot continue it.
+pub(crate) fn literal_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    if bytes.len() >= 3 && bytes[0] == b'\'' && bytes[2] == b'\'' {
+        return 3;
+    }
+    let (body_start, radix) = match (match_prefix(text), bytes) {
+        (Some((prefix, format)), _) => (prefix.len(), format.radix().unwrap_or(10)),
+        (None, [b'0', ..]) => (1, 8),
+        (None, _) => (0, 10),
+    };
+    let body = &bytes[body_start..];
+    let body_len = body.iter()
+        .take_while(|&&b| b == b'_' || char::from(b).is_digit(radix))
+        .count();
+    let len = if body_start > 0 && body[..body_len].iter().all(|&b| b == b'_') {
+        // A bare `0`, possibly followed by a prefix letter without digits.
+        1
+    } else {
+        body_start + body_len
+    };
+    // A Rust type suffix like `u8` belongs to the literal, as in the
+    // default `LiteralParser`, unless it runs on into a longer word. Base
+    // 36 digits already take it in.
+    let suffix_len = RUST_SUFFIXES.iter()
+        .map(|(suffix, _, _)| suffix.as_bytes())
+        .filter(|suffix| bytes[len..].starts_with(suffix))
+        .filter(|suffix| bytes.get(len + suffix.len()).is_none_or(|b| !b.is_ascii_alphanumeric() && *b != b'_'))
+        .map(<[u8]>::len)
+        .max()
+        .unwrap_or(0);
+    len + suffix_len
+}
+
+/// Length in bytes of the decimal float, like `-1.5e3`, at the start of
+/// `text`. An exponent marker only counts if digits follow it, so units
+/// like `em` are left alone.
+pub(crate) fn float_literal_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let count_digits = |from: usize| bytes[from..].iter()
+        .take_while(|&&b| b == b'_' || b.is_ascii_digit())
+        .count();
+
+    let mut len = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let int_len = count_digits(len);
+    len += int_len;
+    let mut frac_len = 0;
+    if bytes.get(len) == Some(&b'.') {
+        frac_len = count_digits(len + 1);
+        if int_len + frac_len > 0 {
+            len += 1 + frac_len;
+        }
+    }
+    if int_len + frac_len == 0 {
+        return 0;
+    }
+    if let Some(b'e' | b'E') = bytes.get(len) {
+        let sign_len = usize::from(matches!(bytes.get(len + 1), Some(b'+' | b'-')));
+        let exponent_len = bytes[len + 1 + sign_len..].iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if exponent_len > 0 {
+            len += 1 + sign_len + exponent_len;
+        }
+    }
+    len
+}
+
+/// Split a leading `+` or `-` off a literal, rejecting a sign without
+/// anything after it and signs that are doubled or followed by space.
+pub(crate) fn split_sign(text: &str) -> Result<(bool, &str), ParseLiteralError> {
+    read_sign(text.as_bytes()).map(|(negative, len)| (negative, &text[len..]))
+}
+
+/// Convert the unsigned digits of a literal with `T::from_str_radix`,
+/// telling apart malformed digits from values that do not fit `T`.
+pub(crate) fn from_digits<T: Num>(negative: bool, digits: &str, radix: u32) -> Result<T, ParseLiteralError> {
+    check_digits(digits, radix)?;
+    let result = if negative {
+        with_formatted(format_args!("-{digits}"), |digits| T::from_str_radix(digits, radix))
+    } else {
+        T::from_str_radix(digits, radix)
+    };
+    result.map_err(|_| {
+        if negative && T::from_str_radix("-1", 10).is_err() {
+            ParseLiteralError::InvalidSign
+        } else {
+            ParseLiteralError::Overflow
+        }
+    })
+}
+
+/// Check that the unsigned digits of a literal are all valid in the radix.
+pub(crate) fn check_digits(digits: &str, radix: u32) -> Result<(), ParseLiteralError> {
+    if digits.is_empty() {
+        Err(ParseLiteralError::Empty)
+    } else if digits.starts_with(['+', '-']) {
+        Err(ParseLiteralError::InvalidSign)
+    } else if !digits.chars().all(|c| c.is_digit(radix)) {
+        Err(ParseLiteralError::InvalidDigit)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a text is a well-formed decimal float, so that a failing
+/// conversion can be reported as out of range rather than malformed.
+pub(crate) fn is_float_syntax(text: &str) -> bool {
+    let text = text.strip_prefix(['+', '-']).unwrap_or(text);
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (text, None),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    let exponent_ok = exponent.is_none_or(|exponent| {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        !exponent.is_empty() && is_digits(exponent)
+    });
+    !(int_part.is_empty() && frac_part.is_empty())
+        && is_digits(int_part)
+        && is_digits(frac_part)
+        && exponent_ok
+}
+
+/// Add a zero to a bare decimal point at the start or end of the mantissa
+/// of float syntax, like in `.5` or `5.e3`, as not every `from_str_radix`
+/// accepts them.
+fn pad_point(text: &str) -> String {
+    let sign_len = usize::from(text.starts_with(['+', '-']));
+    let (sign, unsigned) = text.split_at(sign_len);
+    let (mantissa, exponent) = unsigned.split_at(unsigned.find(['e', 'E']).unwrap_or(unsigned.len()));
+    let leading = if mantissa.starts_with('.') { "0" } else { "" };
+    let trailing = if mantissa.ends_with('.') { "0" } else { "" };
+    format!("{sign}{leading}{mantissa}{trailing}{exponent}")
+}
+
+/// The whole number written in fractional or scientific notation, like
+/// `1.5e3`, or `NotIntegral` if it has a fractional part. The text must be
+/// unsigned float syntax. The value is scaled with checked 128-bit
+/// arithmetic, so a huge exponent is an `Overflow` instead of a huge
+/// string of zeros.
+pub(crate) fn integral_value(text: &str) -> Result<u128, ParseLiteralError> {
+    let (mantissa, exponent) = text.split_once(['e', 'E']).unwrap_or((text, "0"));
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = || int_part.bytes().chain(frac_part.bytes());
+    let trailing_zeros = digits().rev().take_while(|&digit| digit == b'0').count();
+    let significant_len = int_part.len() + frac_part.len() - trailing_zeros;
+    if digits().take(significant_len).all(|digit| digit == b'0') {
+        return Ok(0);
+    }
+    let significant = digits().take(significant_len)
+        .try_fold(0u128, |value, digit| value.checked_mul(10)?.checked_add(u128::from(digit - b'0')));
+
+    let scale = exponent.parse::<isize>()
+        .ok()
+        .and_then(|exponent| exponent.checked_add_unsigned(trailing_zeros))
+        .and_then(|exponent| exponent.checked_sub_unsigned(frac_part.len()));
+    match scale {
+        Some(scale) if scale < 0 => Err(ParseLiteralError::NotIntegral),
+        None if exponent.starts_with('-') => Err(ParseLiteralError::NotIntegral),
+        scale => scale.and_then(|scale| u32::try_from(scale).ok())
+            .and_then(|scale| 10u128.checked_pow(scale))
+            .zip(significant)
+            .and_then(|(power, significant)| significant.checked_mul(power))
+            .ok_or(ParseLiteralError::Overflow),
+    }
+}
+
+/// Rewrite a decimal float in scientific notation (`1.5e3`) into plain
+/// positional notation (`1500`), for number types whose `from_str_radix`
+/// understands a decimal point but not an exponent.
+fn expand_exponent(text: &str) -> Option<String> {
+    let (mantissa, exponent) = text.split_once(['e', 'E'])?;
+    let exponent: isize = exponent.parse().ok()?;
+    if exponent.unsigned_abs() > MAX_SHIFTED_EXPONENT {
+        return None;
+    }
+
+    let (sign, mantissa) = match mantissa.strip_prefix(['+', '-']) {
+        Some(rest) => (&mantissa[..1], rest),
+        None => ("", mantissa),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+
+    let digits = format!("{int_part}{frac_part}");
+    let point = int_part.len() as isize + exponent;
+    let shifted = if point <= 0 {
+        format!("0.{}{digits}", "0".repeat(point.unsigned_abs()))
+    } else if point as usize >= digits.len() {
+        format!("{digits}{}", "0".repeat(point as usize - digits.len()))
+    } else {
+        let (int_part, frac_part) = digits.split_at(point as usize);
+        format!("{int_part}.{frac_part}")
+    };
+    Some(format!("{sign}{shifted}"))
 }
 
 impl<T> NumLiteralTrait<T> for T where T: Num {
-    fn parse_literal(text: &str) -> Result<T, T::FromStrRadixErr> {
-        if text.len() == 3 && text.starts_with("'") && text.ends_with("'") {
-            let chr = text.as_bytes().iter().nth(1).unwrap();            
-            return T::from_str_radix(chr.to_string().as_str(), 10);
+    fn parse_literal(text: impl AsRef<str>) -> Result<T, ParseLiteralError> {
+        LiteralParser::new().parse(text.as_ref())
+    }
+
+    fn parse_literal_prefix(text: impl AsRef<str>) -> Result<(T, usize), ParseLiteralError> {
+        let text = text.as_ref();
+        match literal_len(text) {
+            0 if text.is_empty() => Err(ParseLiteralError::Empty),
+            0 => Err(ParseLiteralError::InvalidDigit),
+            len => T::parse_literal(&text[..len]).map(|value| (value, len)),
+        }
+    }
+
+    fn parse_literal_rest(text: &str) -> Result<(T, &str), ParseLiteralError> {
+        T::parse_literal_prefix(text).map(|(value, len)| (value, &text[len..]))
+    }
+
+    fn parse_literal_all(text: impl AsRef<str>) -> Result<T, ParseLiteralError> {
+        let text = text.as_ref();
+        let trimmed = text.trim();
+        let leading = text.len() - text.trim_start().len();
+        let sign_len = usize::from(trimmed.starts_with(['+', '-']));
+        let len = match literal_len(&trimmed[sign_len..]) {
+            0 => return T::parse_literal(trimmed),
+            len => sign_len + len,
+        };
+        let rest = &trimmed[len..];
+        if !rest.is_empty() {
+            let spaces = rest.len() - rest.trim_start().len();
+            return Err(ParseLiteralError::TrailingCharacters { offset: leading + len + spaces });
+        }
+        T::parse_literal(trimmed)
+    }
+
+    fn parse_literal_details(text: &str) -> Result<LiteralDetails<'_, T>, ParseLiteralError> {
+        let value = T::parse_literal(text)?;
+        let text = text.trim();
+        let sign = match text.as_bytes().first() {
+            Some(b'+') => LiteralSign::ExplicitPlus,
+            Some(b'-') => LiteralSign::Minus,
+            _ => LiteralSign::Implicit,
+        };
+        let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+        // Judge the format without a type suffix, so `0u8` is a decimal.
+        let unsigned = strip_rust_suffix(unsigned).0;
+        let format = detect_format(unsigned);
+        let prefix = match format {
+            LiteralFormat::Decimal | LiteralFormat::Char => "",
+            _ => &unsigned[..unsigned.len() - identify_literal(unsigned).0.len()],
+        };
+        Ok(LiteralDetails::new(value, format, prefix, sign))
+    }
+
+    fn parse_literal_worded(text: impl AsRef<str>) -> Result<T, ParseLiteralError> {
+        let text = text.as_ref().trim();
+        let worded = text.split_once(char::is_whitespace).and_then(|(word, digits)| {
+            let radix = match word.to_ascii_lowercase().as_str() {
+                "bin" => 2,
+                "oct" => 8,
+                "dec" => 10,
+                "hex" => 16,
+                _ => return None,
+            };
+            Some((radix, digits.trim_start()))
+        });
+        match worded {
+            Some((radix, digits)) => {
+                let (negative, digits) = split_sign(digits)?;
+                with_digits(digits, false, |digits| from_digits(negative, digits, radix))
+            },
+            None => T::parse_literal(text),
+        }
+    }
+
+    fn parse_literal_try_radixes(text: impl AsRef<str>, radixes: &[u32]) -> Result<T, ParseLiteralError> {
+        if radixes.is_empty() || radixes.iter().any(|radix| !(2..=36).contains(radix)) {
+            return Err(ParseLiteralError::InvalidRadix);
+        }
+        let (negative, digits) = split_sign(text.as_ref().trim())?;
+        with_digits(digits, false, |digits| {
+            let mut result = Err(ParseLiteralError::InvalidRadix);
+            for &radix in radixes {
+                result = from_digits(negative, digits, radix);
+                if result.is_ok() {
+                    break;
+                }
+            }
+            result
+        })
+    }
+
+    fn parse_value_unit(text: &str) -> Result<(T, &str), ParseLiteralError> {
+        let text = text.trim();
+        let sign_len = usize::from(text.starts_with(['+', '-']));
+        let int_len = match literal_len(&text[sign_len..]) {
+            0 => 0,
+            len => sign_len + len,
+        };
+        let float_len = float_literal_len(text);
+        let (value, len) = if float_len > int_len {
+            (T::parse_float_literal(&text[..float_len])?, float_len)
+        } else if int_len > 0 {
+            (T::parse_literal(&text[..int_len])?, int_len)
+        } else if text.is_empty() {
+            return Err(ParseLiteralError::Empty);
         } else {
-            let (num_part, radix) = identify_literal(text);        
-            return T::from_str_radix(&num_part.replace("_", ""), radix);
+            return Err(ParseLiteralError::InvalidDigit);
+        };
+        Ok((value, text[len..].trim_start()))
+    }
+
+    fn parse_literal_le(text: impl AsRef<str>) -> Result<T, ParseLiteralError> {
+        let text = text.as_ref();
+        let digits_per_byte = match detect_format(text) {
+            LiteralFormat::Binary => 8,
+            LiteralFormat::Hexadecimal => 2,
+            _ => return Err(ParseLiteralError::UnsupportedFormat),
+        };
+        let (num_part, radix) = identify_literal(text);
+        with_digits(num_part, false, |digits| {
+            if !digits.len().is_multiple_of(digits_per_byte) {
+                return Err(ParseLiteralError::IncompleteByte);
+            }
+            if !digits.is_ascii() {
+                return Err(ParseLiteralError::InvalidDigit);
+            }
+            let reversed: String = digits.as_bytes()
+                .rchunks(digits_per_byte)
+                .flat_map(|byte| byte.iter().map(|&b| char::from(b)))
+                .collect();
+            from_digits(false, &reversed, radix)
+        })
+    }
+
+    fn parse_literal_fallback(text: impl AsRef<str>, fallback: T) -> T {
+        T::parse_literal(text).unwrap_or(fallback)
+    }
+
+    fn parse_literal_saturating(text: impl AsRef<str>) -> Result<T, ParseLiteralError> where T: Bounded {
+        let text = text.as_ref();
+        match T::parse_literal(text) {
+            Err(ParseLiteralError::Overflow) if text.trim_start().starts_with('-') => Ok(T::min_value()),
+            Err(ParseLiteralError::Overflow) => Ok(T::max_value()),
+            result => result,
         }
     }
-    
-    fn parse_literal_fallback(text: &str, fallback: T) -> T {
-        if text.len() == 3 && text.starts_with("'") && text.ends_with("'") {
-            let chr = text.as_bytes().iter().nth(1).unwrap();            
-            return T::from_str_radix(chr.to_string().as_str(), 10).unwrap_or(fallback);
+
+    fn parse_float_literal(text: impl AsRef<str>) -> Result<T, ParseLiteralError> {
+        let text = text.as_ref().trim().replace("_", "");
+        if text.is_empty() {
+            return Err(ParseLiteralError::Empty);
+        }
+        let (negative, _) = split_sign(&text)?;
+        if !is_float_syntax(&text) {
+            // Also keeps `inf` and `NaN`, which some `from_str_radix` accept.
+            return Err(ParseLiteralError::InvalidDigit);
+        }
+        if let Ok(value) = T::from_str_radix(&text, 10) {
+            return Ok(value);
+        }
+        let padded = Some(pad_point(&text)).filter(|padded| *padded != text);
+        if let Some(Ok(value)) = padded.as_deref().map(|padded| T::from_str_radix(padded, 10)) {
+            return Ok(value);
+        }
+        let expanded = expand_exponent(&text);
+        if let Some(Ok(value)) = expanded.as_deref().map(|expanded| T::from_str_radix(expanded, 10)) {
+            return Ok(value);
+        }
+        let positional = expanded.as_deref().unwrap_or(&text);
+        if positional.contains('.') && T::from_str_radix("0.5", 10).is_err() {
+            // A fraction for a type that only holds integers.
+            Err(ParseLiteralError::InvalidDigit)
+        } else if negative && T::from_str_radix("-1", 10).is_err() {
+            Err(ParseLiteralError::InvalidSign)
         } else {
-            let (num_part, radix) = identify_literal(text);        
-            return T::from_str_radix(&num_part.replace("_", ""), radix).unwrap_or(fallback); 
+            Err(ParseLiteralError::Overflow)
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,1161 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::{LiteralFormat, LiteralTarget, ParseLiteralError};
+use crate::constant::fit;
+use crate::format::match_prefix;
+use crate::scan::{group_index, read_body, read_digits, with_digits, with_formatted, Body, Grammar};
+use crate::target::NumTarget;
+use crate::parse_literal::{integral_value, is_float_syntax};
+
+/// A literal parser with configurable rules.
+///
+/// `LiteralParser::new()` parses exactly like `parse_literal`, the
+/// builder methods tighten or extend the accepted input.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{LiteralParser, ParseLiteralError};
+///
+/// let parser = LiteralParser::new().strict(true);
+/// assert_eq!(parser.parse::<u32>("0xFF"), Ok(255));
+/// assert_eq!(parser.parse::<u32>("0x_FF"), Err(ParseLiteralError::SeparatorAfterPrefix));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteralParser {
+    grammar: Grammar,
+    char_literals: bool,
+    integer_exponent: bool,
+    guess_hex: bool,
+    inline_radix: bool,
+    trim: bool,
+    exact_type: bool,
+    max_len: Option<usize>,
+    double_quoted_chars: bool,
+    char_delimiter: char,
+}
+
+impl LiteralParser {
+    /// A parser with the lenient rules of `parse_literal`.
+    pub fn new() -> Self {
+        Self {
+            grammar: Grammar::DEFAULT,
+            char_literals: true,
+            integer_exponent: false,
+            guess_hex: false,
+            inline_radix: false,
+            trim: true,
+            exact_type: false,
+            max_len: None,
+            double_quoted_chars: false,
+            char_delimiter: '\'',
+        }
+    }
+
+    /// A parser for integer literals as written in Python 3.
+    ///
+    /// Octal needs the `0o` prefix, since Python rejects C-style octal
+    /// like `0123` (`LeadingZero`), while zeros like `00` stay valid.
+    /// Separators `_` may follow the prefix or sit between digits, but
+    /// never lead, trail or double up (`MisplacedSeparator`). Char
+    /// literals are not accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_literal_traits::{LiteralParser, ParseLiteralError};
+    ///
+    /// let parser = LiteralParser::python();
+    /// assert_eq!(parser.parse::<u32>("0o777"), Ok(511));
+    /// assert_eq!(parser.parse::<u32>("0x_FF"), Ok(255));
+    /// assert_eq!(parser.parse::<u32>("0123"), Err(ParseLiteralError::LeadingZero));
+    /// ```
+    pub fn python() -> Self {
+        Self::new()
+            .base36_prefix(false)
+            .h_prefix(false)
+            .separators_between_digits(true)
+            .leading_zero_octal(false)
+            .char_literals(false)
+            .rust_suffixes(false)
+    }
+
+    /// A parser for integer literals as written in Go.
+    ///
+    /// Both `0o17` and the legacy `017` are octal. Separators `_` may
+    /// follow the prefix, including the legacy octal `0`, or sit between
+    /// digits, but never lead, trail or double up (`MisplacedSeparator`).
+    /// Rune literals are accepted as char literals, limited to ASCII.
+    /// Go's hexadecimal floats are not covered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_literal_traits::{LiteralParser, ParseLiteralError};
+    ///
+    /// let parser = LiteralParser::go();
+    /// assert_eq!(parser.parse::<u32>("0o17"), Ok(15));
+    /// assert_eq!(parser.parse::<u32>("0_600"), Ok(384));
+    /// assert_eq!(parser.parse::<u32>("0x_1_"), Err(ParseLiteralError::MisplacedSeparator));
+    /// ```
+    pub fn go() -> Self {
+        Self::new().base36_prefix(false).h_prefix(false).rust_suffixes(false).separators_between_digits(true)
+    }
+
+    /// A parser for integer literals as written in C, C++ and Java, with
+    /// their size and signedness suffixes.
+    ///
+    /// See [`c_suffixes`](Self::c_suffixes) for the accepted suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_literal_traits::{LiteralParser, ParseLiteralError};
+    ///
+    /// let parser = LiteralParser::c();
+    /// assert_eq!(parser.parse::<u64>("0xFFUL"), Ok(255));
+    /// assert_eq!(parser.parse::<u64>("123UU"), Err(ParseLiteralError::InvalidSuffix));
+    /// ```
+    pub fn c() -> Self {
+        Self::new().base36_prefix(false).h_prefix(false).rust_suffixes(false).c_suffixes(true)
+    }
+
+    /// Reject input that is accepted leniently but likely a typo: a
+    /// separator directly after the radix prefix in `0x_FF`, and
+    /// separators that are not between digits, like in `1__000`.
+    /// Disabled by default.
+    #[must_use]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.grammar.strict = strict;
+        self
+    }
+
+    /// Only accept digit separators `_` between digits or directly after
+    /// the radix prefix. Disabled by default, which removes separators
+    /// wherever they are.
+    #[must_use]
+    pub fn separators_between_digits(mut self, between_digits: bool) -> Self {
+        self.grammar.separators_between_digits = between_digits;
+        self
+    }
+
+    /// Read numbers with a leading zero, like `0123`, as octal (default).
+    /// When disabled, only `0o` selects octal and a leading zero before
+    /// other digits is a `LeadingZero` error.
+    #[must_use]
+    pub fn leading_zero_octal(mut self, octal: bool) -> Self {
+        self.grammar.leading_zero_octal = octal;
+        self
+    }
+
+    /// Strip the C integer suffixes `U`, `L`, `LL` and their combinations
+    /// `UL`, `LU`, `ULL`, `LLU` before converting, in either case. As in
+    /// C, `U` may appear once and `LL` must not mix cases, so `UU` or `lL`
+    /// are an `InvalidSuffix` error. The suffix does not restrict the
+    /// value range, the target type does. Disabled by default.
+    #[must_use]
+    pub fn c_suffixes(mut self, c_suffixes: bool) -> Self {
+        self.grammar.c_suffixes = c_suffixes;
+        self
+    }
+
+    /// Strip the `n` suffix of JavaScript BigInt literals, like `123n` or
+    /// `0xFFn`, before converting. Literals without the suffix still
+    /// parse. JavaScript only allows the suffix on integers, so a number
+    /// with a fraction or exponent like `1.5n` is an `InvalidSuffix`
+    /// error. Disabled by default.
+    #[must_use]
+    pub fn bigint_suffix(mut self, bigint_suffix: bool) -> Self {
+        self.grammar.bigint_suffix = bigint_suffix;
+        self
+    }
+
+    /// Accept whole numbers written in decimal scientific or fractional
+    /// notation, like `1e3` or `1.5e1`. A value with a fractional part,
+    /// like `1e-1`, is a `NotIntegral` error, and a value that does not
+    /// fit the target type or 128 bits is an `Overflow` error, found with
+    /// checked arithmetic even for exponents like `1e40000`. Disabled by
+    /// default.
+    #[must_use]
+    pub fn integer_exponent(mut self, integer_exponent: bool) -> Self {
+        self.integer_exponent = integer_exponent;
+        self
+    }
+
+    /// Read a number as hexadecimal when it lost its `0x` prefix: if the
+    /// regular rules reject its digits, but they are all hex digits and
+    /// include a letter `A`-`F`, it is converted as hex. This is
+    /// ambiguous by nature: `"CAFE"` becomes 51966, but `"1234"` stays
+    /// decimal and `"0b10"` stays binary, and a value like `"10"` meant as
+    /// hex cannot be told apart. Only numbers without a radix prefix are
+    /// guessed, so a malformed `"0b12"` stays an error instead of turning
+    /// into 0xB12, and neither is decimal exponent notation like `"1e3"`.
+    /// Only enable it for input known to be hex or decimal. Disabled by
+    /// default.
+    #[must_use]
+    pub fn guess_hex(mut self, guess_hex: bool) -> Self {
+        self.guess_hex = guess_hex;
+        self
+    }
+
+    /// Accept an explicit radix from 2 to 36 written in decimal before a
+    /// colon, like `7:123` for "123" in base 7 or `36:Z` for 35. A radix
+    /// outside that range is an `InvalidRadix` error. Only digits may
+    /// precede the colon, so words like `hex:` keep their own meaning.
+    /// Disabled by default.
+    #[must_use]
+    pub fn inline_radix(mut self, inline_radix: bool) -> Self {
+        self.inline_radix = inline_radix;
+        self
+    }
+
+    /// Accept the base-36 prefix `0z`, like `0zZ9` (default). The
+    /// language presets disable it, as the languages have no such prefix.
+    #[must_use]
+    pub fn base36_prefix(mut self, base36_prefix: bool) -> Self {
+        self.grammar.base36_prefix = base36_prefix;
+        self
+    }
+
+    /// Accept the Motorola assembler prefix `%` for binary, like `%1010`
+    /// for 10. It behaves like `0b`, an optional sign still goes before
+    /// it. A `%` only ever reads as this prefix, never as a percent sign
+    /// after a number, so keep it disabled for input with percentages
+    /// like `"25%"`. Disabled by default.
+    #[must_use]
+    pub fn percent_binary(mut self, percent_binary: bool) -> Self {
+        self.grammar.percent_binary = percent_binary;
+        self
+    }
+
+    /// Ignore whitespace around the literal, like in `"  0xFF  "`
+    /// (default). When disabled, whitespace before or after the literal is
+    /// a `LeadingWhitespace` or `TrailingWhitespace` error, as needed for
+    /// fixed-format fields.
+    #[must_use]
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Report values that do not fit the target type as `OutOfRange`,
+    /// holding the literal as written, like `"300"` for a `u8`, instead of
+    /// a plain `Overflow`. Literals with too many digits for the type are
+    /// rejected from their digit count alone, before converting. Disabled
+    /// by default.
+    #[must_use]
+    pub fn exact_type(mut self, exact_type: bool) -> Self {
+        self.exact_type = exact_type;
+        self
+    }
+
+    /// Reject texts longer than `max_len` bytes, including surrounding
+    /// whitespace, with a `TooLong` error before looking at them. This
+    /// bounds the work spent on untrusted input. Unlimited by default.
+    #[must_use]
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Accept the hexadecimal prefix `0h`, like `0hFF` (default). The
+    /// language presets disable it, as the languages have no such prefix.
+    #[must_use]
+    pub fn h_prefix(mut self, h_prefix: bool) -> Self {
+        self.grammar.h_prefix = h_prefix;
+        self
+    }
+
+    /// Accept a sign after the number instead of before it, like `123-`
+    /// for -123 or `123+`, as written by COBOL and mainframe exports. A
+    /// leading sign still works, but a number with both, like `-123-`, is
+    /// an `InvalidSign` error. Disabled by default.
+    #[must_use]
+    pub fn trailing_sign(mut self, trailing_sign: bool) -> Self {
+        self.grammar.trailing_sign = trailing_sign;
+        self
+    }
+
+    /// Require the digits of one format to be separated by `_` into groups
+    /// of `size`, counted from the least significant digit, the way
+    /// `FormatOptions::group_size` writes them: every group has exactly
+    /// `size` digits except the first, which has 1 to `size`. With a size
+    /// of 2, `0xDE_AD_BE_EF` and `0xF_FF` parse, but `0xDEAD_BEEF` and
+    /// `0xDEADBEEF` are an `InvalidGrouping` error. A size of `0` allows
+    /// any grouping for that format (default). Char literals are never
+    /// grouped.
+    #[must_use]
+    pub fn group_size(mut self, format: LiteralFormat, size: usize) -> Self {
+        if let Some(radix) = format.radix() {
+            self.grammar.group_sizes[group_index(radix)] = size;
+        }
+        self
+    }
+
+    /// Accept char literals like `'A'` (default).
+    #[must_use]
+    pub fn char_literals(mut self, char_literals: bool) -> Self {
+        self.char_literals = char_literals;
+        self
+    }
+
+    /// Accept a single ASCII character in double quotes, like `"A"`, as a
+    /// char literal. More than one character, like `"AB"`, is still an
+    /// error. This works independently of
+    /// [`char_literals`](Self::char_literals). Disabled by default.
+    #[must_use]
+    pub fn double_quoted_chars(mut self, double_quoted_chars: bool) -> Self {
+        self.double_quoted_chars = double_quoted_chars;
+        self
+    }
+
+    /// Quote char literals with `delimiter` instead of `'`, like `` `A` ``
+    /// for a backtick, for formats that use the apostrophe otherwise. A
+    /// literal in single quotes is then no longer a char literal. Only has
+    /// an effect while [`char_literals`](Self::char_literals) is enabled.
+    #[must_use]
+    pub fn char_delimiter(mut self, delimiter: char) -> Self {
+        self.char_delimiter = delimiter;
+        self
+    }
+
+    /// Only accept literals in one of `formats`, like decimal and
+    /// hexadecimal but never octal. A literal in another format is a
+    /// `FormatNotAllowed` error, even if it would parse otherwise, so
+    /// `0755` is rejected unless octal is allowed. With
+    /// [`inline_radix`](Self::inline_radix), a radix stands for the format
+    /// with that radix, and radixes like 7 without a format are rejected.
+    /// All formats are allowed by default.
+    #[must_use]
+    pub fn allowed_formats(mut self, formats: &[LiteralFormat]) -> Self {
+        self.grammar.formats = formats.iter().fold(0, |bits, format| bits | 1 << format.radix().unwrap_or(0));
+        self
+    }
+
+    /// Accept the Unicode spaces that locales group digits with, like the
+    /// narrow no-break space in `1\u{202F}234\u{202F}567` copied from a
+    /// localized UI, as separators. They count as `_`, so strict mode and
+    /// [`group_size`](Self::group_size) check them the same way, and like
+    /// any whitespace they cannot follow the sign. Only `_` separates digits
+    /// by default.
+    #[must_use]
+    pub fn space_separators(mut self, space_separators: bool) -> Self {
+        self.grammar.space_separators = space_separators;
+        self
+    }
+
+    /// Require every number to carry an explicit sign, like `+5` or `-5`,
+    /// as signed fixed-format fields do. A number without one, like `5`,
+    /// is a `MissingSign` error. A trailing sign from
+    /// [`trailing_sign`](Self::trailing_sign) counts, char literals cannot
+    /// carry a sign and are not affected. Disabled by default.
+    #[must_use]
+    pub fn require_sign(mut self, require_sign: bool) -> Self {
+        self.grammar.require_sign = require_sign;
+        self
+    }
+
+    /// Accept the Rust integer type suffixes, like `u8` in `0xFFu8` or
+    /// `i64` in `-42i64`, lowercase as in Rust (default). The value must
+    /// fit the type of the suffix as well as the target type, so `0x100u8`
+    /// is an `Overflow` even when parsed as `u32`, and `-1u8` is an
+    /// `InvalidSign`. Base 36 literals end in digits like `u` and `8`, so
+    /// they never have a suffix. The language presets disable it, as the
+    /// languages have no such suffixes.
+    #[must_use]
+    pub fn rust_suffixes(mut self, rust_suffixes: bool) -> Self {
+        self.grammar.rust_suffixes = rust_suffixes;
+        self
+    }
+
+    /// Check that the format with `radix`, or `Char` for `None`, is
+    /// allowed.
+    fn check_format(&self, radix: Option<u32>) -> Result<(), ParseLiteralError> {
+        if self.grammar.allows(radix) {
+            Ok(())
+        } else {
+            Err(ParseLiteralError::FormatNotAllowed)
+        }
+    }
+
+    /// Determine the literal type, then convert to a number value or
+    /// return an error.
+    ///
+    /// # Arguments
+    /// - `text`: Textual representation of a number.
+    /// # Returns
+    /// - Numerical result or error.
+    pub fn parse<T: Num>(&self, text: &str) -> Result<T, ParseLiteralError> {
+        self.parse_into::<NumTarget<T>>(text).map(|NumTarget(value)| value)
+    }
+
+    /// Convert a literal to any `LiteralTarget`, which builds the value
+    /// from the parts the rules take apart.
+    pub(crate) fn parse_into<T: LiteralTarget>(&self, text: &str) -> Result<T, ParseLiteralError> {
+        if self.max_len.is_some_and(|max_len| text.len() > max_len) {
+            return Err(ParseLiteralError::TooLong);
+        }
+        match self.parse_text(text) {
+            Err(ParseLiteralError::Overflow) if self.exact_type => {
+                Err(ParseLiteralError::OutOfRange { value: text.trim().to_string() })
+            },
+            result => result,
+        }
+    }
+
+    /// Convert a literal with all enabled rules.
+    fn parse_text<T: LiteralTarget>(&self, text: &str) -> Result<T, ParseLiteralError> {
+        let text = if self.trim {
+            text.trim()
+        } else if text.starts_with(char::is_whitespace) {
+            return Err(ParseLiteralError::LeadingWhitespace);
+        } else if text.ends_with(char::is_whitespace) {
+            return Err(ParseLiteralError::TrailingWhitespace);
+        } else {
+            text
+        };
+        let quoted = |quote| text.strip_prefix(quote)?.strip_suffix(quote).filter(|chr| chr.len() == 1);
+        let chr = quoted(self.char_delimiter).filter(|_| self.char_literals)
+            .or_else(|| quoted('"').filter(|_| self.double_quoted_chars));
+        if let Some(chr) = chr {
+            self.check_format(None)?;
+            return with_formatted(format_args!("{}", chr.as_bytes()[0]), |digits| {
+                T::from_literal_parts(false, digits, 10)
+            });
+        }
+
+        let body = read_body(text.as_bytes(), &self.grammar)?;
+        let (negative, rest) = (body.negative, &text[body.start..body.end]);
+        if self.inline_radix
+            && let Some((radix, _)) = rest.split_once(':')
+            && !radix.is_empty()
+            && radix.bytes().all(|b| b.is_ascii_digit())
+        {
+            let digits = Body { start: body.start + radix.len() + 1, ..body };
+            let radix = radix.parse().ok().filter(|radix| (2..=36).contains(radix))
+                .ok_or(ParseLiteralError::InvalidRadix)?;
+            return self.convert(text, &digits, &Grammar { fixed_radix: Some(radix), ..self.grammar });
+        }
+        let spaces = self.grammar.space_separators;
+        if self.integer_exponent
+            && rest.contains(['.', 'e', 'E'])
+            && let Some(value) = with_digits(rest, spaces, |plain| {
+                (is_float_syntax(plain) && !plain.starts_with(['+', '-'])).then(|| integral_value(plain))
+            })
+        {
+            self.check_format(Some(10))?;
+            let value = value?;
+            return with_formatted(format_args!("{value}"), |digits| T::from_literal_parts(negative, digits, 10));
+        }
+        let result = self.convert(text, &body, &self.grammar);
+        let prefixed = match_prefix(rest).is_some() || (self.grammar.percent_binary && rest.starts_with('%'));
+        if self.guess_hex && !prefixed && matches!(result, Err(ParseLiteralError::InvalidDigit)) {
+            return with_digits(rest, spaces, |digits| {
+                if is_float_syntax(digits)
+                    || !digits.chars().all(|c| c.is_ascii_hexdigit())
+                    || !digits.chars().any(|c| c.is_ascii_alphabetic())
+                {
+                    return result;
+                }
+                self.check_format(Some(16))?;
+                T::from_literal_parts(negative, digits, 16)
+            });
+        }
+        result
+    }
+
+    /// Convert the digits of a literal body with `grammar`.
+    fn convert<T: LiteralTarget>(&self, text: &str, body: &Body, grammar: &Grammar) -> Result<T, ParseLiteralError> {
+        let digits = read_digits(text.as_bytes(), body, grammar)?;
+        if self.exact_type && exceeds_width::<T>(body.negative, digits.significant, digits.radix) {
+            return Err(ParseLiteralError::Overflow);
+        }
+        let region = &text[digits.start..digits.end];
+        let value = if digits.separated {
+            with_digits(region, grammar.space_separators, |region| {
+                T::from_literal_parts(body.negative, region, digits.radix)
+            })?
+        } else {
+            T::from_literal_parts(body.negative, region, digits.radix)?
+        };
+        if let Some((min, max)) = digits.suffix {
+            fit(body.negative, digits.magnitude.ok_or(ParseLiteralError::Overflow)?, min, max)?;
+        }
+        Ok(value)
+    }
+}
+
+/// The number of value bits of an integer type, found by probing which
+/// powers of two it can hold, or `None` if it holds them all.
+fn value_bits<T: LiteralTarget>() -> Option<u32> {
+    const BITS: &str = concat!("1", "0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000000");
+    [7, 8, 15, 16, 31, 32, 63, 64, 127, 128].into_iter()
+        .find(|&bits| T::from_literal_parts(false, &BITS[..=bits], 2).is_err())
+        .map(|bits| bits as u32)
+}
+
+/// Whether valid digits with `significant` places after their leading
+/// zeros are too many to fit `T`: such a number is at least
+/// `radix^(significant - 1)`.
+fn exceeds_width<T: LiteralTarget>(negative: bool, significant: usize, radix: u32) -> bool {
+    if significant == 0 {
+        return false;
+    }
+    let Some(bits) = value_bits::<T>() else {
+        return false;
+    };
+    let min_bits = ((significant - 1) as f64 * f64::from(radix).log2()).floor() as u32 + 1;
+    min_bits > bits + u32::from(negative)
+}
+
+impl Default for LiteralParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NumLiteralTrait;
+
+    #[test]
+    fn strict_separator_after_prefix_fails() {
+        let parser = LiteralParser::new().strict(true);
+        for text in ["0x_FF", "0b_1010", "0_17", "-0X__1"] {
+            assert_eq!(parser.parse::<i32>(text), Err(ParseLiteralError::SeparatorAfterPrefix), "{text}");
+        }
+    }
+
+    #[test]
+    fn strict_separator_between_digits_works() {
+        let parser = LiteralParser::new().strict(true);
+        assert_eq!(parser.parse::<u32>("0xF_F"), Ok(255));
+        assert_eq!(parser.parse::<u32>("0b10_10"), Ok(10));
+        assert_eq!(parser.parse::<u32>("1_000"), Ok(1000));
+    }
+
+    #[test]
+    fn strict_misplaced_separator_fails() {
+        let parser = LiteralParser::new().strict(true);
+        for text in ["_12", "12_", "1__2", "0xF__F", "0b1_"] {
+            assert_eq!(parser.parse::<i32>(text), Err(ParseLiteralError::MisplacedSeparator), "{text}");
+        }
+    }
+
+    #[test]
+    fn group_size_2_works() {
+        let parser = LiteralParser::new().group_size(LiteralFormat::Hexadecimal, 2);
+        assert_eq!(parser.parse::<u32>("0xDE_AD_BE_EF"), Ok(0xDEADBEEF));
+        assert_eq!(parser.parse::<u32>("0xF_FF"), Ok(0xFFF));
+        assert_eq!(parser.parse::<u32>("0xFF"), Ok(0xFF));
+        assert_eq!(parser.parse::<u32>("0xF"), Ok(0xF));
+        assert_eq!(parser.parse::<u32>("1234_5"), Ok(12345));
+    }
+
+    #[test]
+    fn group_size_2_misgrouped_fails() {
+        let parser = LiteralParser::new().group_size(LiteralFormat::Hexadecimal, 2);
+        for text in ["0xDEAD_BEEF", "0xDEADBEEF", "0xDE_A_BE", "0xDE_ADB", "0x_FF", "0xFF_", "0xF__F"] {
+            assert_eq!(parser.parse::<u32>(text), Err(ParseLiteralError::InvalidGrouping), "{text}");
+        }
+    }
+
+    #[test]
+    fn group_size_4_works() {
+        let parser = LiteralParser::new()
+            .group_size(LiteralFormat::Hexadecimal, 4)
+            .group_size(LiteralFormat::Binary, 4);
+        assert_eq!(parser.parse::<u32>("0xDEAD_BEEF"), Ok(0xDEADBEEF));
+        assert_eq!(parser.parse::<u32>("0xD_BEEF"), Ok(0xDBEEF));
+        assert_eq!(parser.parse::<u8>("0b10_0101"), Ok(0b10_0101));
+        assert_eq!(parser.parse::<u8>("%1010_0101"), Err(ParseLiteralError::InvalidDigit));
+        let parser = parser.percent_binary(true);
+        assert_eq!(parser.parse::<u8>("%1010_0101"), Ok(0b1010_0101));
+    }
+
+    #[test]
+    fn group_size_4_misgrouped_fails() {
+        let parser = LiteralParser::new()
+            .group_size(LiteralFormat::Hexadecimal, 4)
+            .group_size(LiteralFormat::Decimal, 3);
+        for text in ["0xDE_AD_BE_EF", "0xDEADBEEF", "0xDEAD_BEE", "1_0000", "1000000"] {
+            assert_eq!(parser.parse::<u32>(text), Err(ParseLiteralError::InvalidGrouping), "{text}");
+        }
+        assert_eq!(parser.parse::<u32>("1_000_000"), Ok(1_000_000));
+    }
+
+    #[test]
+    fn trailing_sign_works() {
+        let parser = LiteralParser::new().trailing_sign(true);
+        assert_eq!(parser.parse::<i32>("123-"), Ok(-123));
+        assert_eq!(parser.parse::<i32>("123+"), Ok(123));
+        assert_eq!(parser.parse::<i32>(" 0xFF- "), Ok(-255));
+        assert_eq!(parser.parse::<u32>("123+"), Ok(123));
+        assert_eq!(parser.parse::<i32>("-123"), Ok(-123));
+        assert_eq!(parser.parse::<i32>("123"), Ok(123));
+        assert_eq!(parser.parse::<i8>("128-"), Ok(-128));
+    }
+
+    #[test]
+    fn trailing_minus_unsigned_fails() {
+        let parser = LiteralParser::new().trailing_sign(true);
+        assert_eq!(parser.parse::<u32>("123-"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(parser.parse::<u32>("0-"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn trailing_sign_conflicting_fails() {
+        let parser = LiteralParser::new().trailing_sign(true);
+        for text in ["-123-", "+123-", "-123+", "123--", "123+-"] {
+            assert_eq!(parser.parse::<i32>(text), Err(ParseLiteralError::InvalidSign), "{text}");
+        }
+        assert_eq!(LiteralParser::new().parse::<i32>("123-"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn double_quoted_chars_work() {
+        let parser = LiteralParser::new().double_quoted_chars(true);
+        assert_eq!(parser.parse::<u8>("\"A\""), Ok(65));
+        assert_eq!(parser.parse::<u8>(" \"0\" "), Ok(48));
+        assert_eq!(parser.parse::<u8>("'A'"), Ok(65));
+        assert_eq!(parser.char_literals(false).parse::<u8>("\"A\""), Ok(65));
+    }
+
+    #[test]
+    fn double_quoted_chars_fail() {
+        let parser = LiteralParser::new().double_quoted_chars(true);
+        assert_eq!(parser.parse::<u8>("\"AB\""), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u8>("\"\""), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u8>("\"A'"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u32>("\"全\""), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(LiteralParser::new().parse::<u8>("\"A\""), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn char_delimiter_works() {
+        let parser = LiteralParser::new().char_delimiter('`');
+        assert_eq!(parser.parse::<u8>("`A`"), Ok(65));
+        assert_eq!(parser.parse::<u8>(" `'` "), Ok(39));
+        assert_eq!(parser.parse::<u8>("```"), Ok(96));
+        assert_eq!(parser.clone().double_quoted_chars(true).parse::<u8>("\"A\""), Ok(65));
+        assert_eq!(LiteralParser::new().char_delimiter('\'').parse::<u8>("'A'"), Ok(65));
+    }
+
+    #[test]
+    fn char_delimiter_fails() {
+        let parser = LiteralParser::new().char_delimiter('`');
+        assert_eq!(parser.parse::<u8>("'A'"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u8>("`AB`"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u8>("`A'"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u8>("``"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.char_literals(false).parse::<u8>("`A`"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn allowed_formats_work() {
+        let parser = LiteralParser::new().allowed_formats(&[LiteralFormat::Decimal, LiteralFormat::Hexadecimal]);
+        assert_eq!(parser.parse::<u32>("123"), Ok(123));
+        assert_eq!(parser.parse::<i32>(" -0 "), Ok(0));
+        assert_eq!(parser.parse::<u32>("0xFF"), Ok(255));
+        assert_eq!(parser.parse::<u32>("0hFF"), Ok(255));
+        assert_eq!(parser.parse::<u32>("0xFG"), Err(ParseLiteralError::InvalidDigit));
+        let parser = parser.inline_radix(true).integer_exponent(true).guess_hex(true);
+        assert_eq!(parser.parse::<u32>("16:FF"), Ok(255));
+        assert_eq!(parser.parse::<u32>("1e3"), Ok(1000));
+        assert_eq!(parser.parse::<u32>("CAFE"), Ok(0xCAFE));
+    }
+
+    #[test]
+    fn allowed_formats_reject_others() {
+        let parser = LiteralParser::new().allowed_formats(&[LiteralFormat::Decimal, LiteralFormat::Hexadecimal]);
+        for text in ["0755", "0o17", "00", "0b101", "0zZ", "'A'", "-0755", "0o9"] {
+            assert_eq!(parser.parse::<i32>(text), Err(ParseLiteralError::FormatNotAllowed), "{text}");
+        }
+        let parser = parser.inline_radix(true);
+        assert_eq!(parser.parse::<u32>("8:17"), Err(ParseLiteralError::FormatNotAllowed));
+        assert_eq!(parser.parse::<u32>("7:12"), Err(ParseLiteralError::FormatNotAllowed));
+        let parser = LiteralParser::new().allowed_formats(&[LiteralFormat::Hexadecimal]);
+        assert_eq!(parser.parse::<u32>("123"), Err(ParseLiteralError::FormatNotAllowed));
+        assert_eq!(parser.parse::<u32>("0"), Err(ParseLiteralError::FormatNotAllowed));
+        let parser = LiteralParser::new().allowed_formats(&[LiteralFormat::Char]);
+        assert_eq!(parser.parse::<u8>("'A'"), Ok(65));
+        assert_eq!(parser.parse::<u8>("65"), Err(ParseLiteralError::FormatNotAllowed));
+    }
+
+    #[test]
+    fn space_separators_work() {
+        let parser = LiteralParser::new().space_separators(true);
+        assert_eq!(parser.parse::<u32>("1\u{202F}234\u{202F}567"), Ok(1_234_567));
+        assert_eq!(parser.parse::<i32>(" -1\u{202F}234 "), Ok(-1234));
+        assert_eq!(parser.parse::<u32>("1\u{2009}000"), Ok(1000));
+        assert_eq!(parser.parse::<u32>("1\u{00A0}000"), Ok(1000));
+        assert_eq!(parser.parse::<u32>("0xFF\u{202F}FF"), Ok(0xFFFF));
+        let grouped = parser.group_size(LiteralFormat::Decimal, 3);
+        assert_eq!(grouped.parse::<u32>("12\u{202F}345\u{202F}678"), Ok(12_345_678));
+        assert_eq!(grouped.parse::<u32>("1234\u{202F}567"), Err(ParseLiteralError::InvalidGrouping));
+    }
+
+    #[test]
+    fn space_separators_fail() {
+        assert_eq!(LiteralParser::new().parse::<u32>("1\u{202F}234"), Err(ParseLiteralError::InvalidDigit));
+        let parser = LiteralParser::new().space_separators(true);
+        assert_eq!(parser.parse::<u32>("1 234"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<i32>("-\u{202F}1"), Err(ParseLiteralError::InvalidSign));
+        let strict = parser.strict(true);
+        assert_eq!(strict.parse::<u32>("1\u{202F}\u{202F}234"), Err(ParseLiteralError::MisplacedSeparator));
+        assert_eq!(strict.parse::<u32>("1\u{202F}_234"), Err(ParseLiteralError::MisplacedSeparator));
+    }
+
+    #[test]
+    fn require_sign_works() {
+        let parser = LiteralParser::new().require_sign(true);
+        assert_eq!(parser.parse::<i32>("+5"), Ok(5));
+        assert_eq!(parser.parse::<i32>("-5"), Ok(-5));
+        assert_eq!(parser.parse::<i32>(" -0x10 "), Ok(-16));
+        assert_eq!(parser.parse::<u8>("'A'"), Ok(65));
+        assert_eq!(parser.clone().trailing_sign(true).parse::<i32>("5-"), Ok(-5));
+        assert_eq!(parser.parse::<i32>("+"), Err(ParseLiteralError::LoneSign));
+        assert_eq!(parser.parse::<i32>(""), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn require_sign_missing_fails() {
+        let parser = LiteralParser::new().require_sign(true);
+        assert_eq!(parser.parse::<i32>("5"), Err(ParseLiteralError::MissingSign));
+        assert_eq!(parser.parse::<u32>("0xFF"), Err(ParseLiteralError::MissingSign));
+        assert_eq!(parser.parse::<i32>(" 0 "), Err(ParseLiteralError::MissingSign));
+        assert_eq!(parser.trailing_sign(true).parse::<i32>("5"), Err(ParseLiteralError::MissingSign));
+    }
+
+    #[test]
+    fn require_sign_disabled_by_default() {
+        assert_eq!(LiteralParser::new().parse::<i32>("5"), Ok(5));
+        assert_eq!(i32::parse_literal("5"), Ok(5));
+    }
+
+    #[test]
+    fn rust_suffixes_work() {
+        let parser = LiteralParser::new();
+        let cases = [
+            ("0xFFu8", 255),
+            ("0Xffu8", 255),
+            ("0b1010u16", 10),
+            ("0o17u32", 15),
+            ("017u64", 15),
+            ("0hFFusize", 255),
+            ("255u128", 255),
+            ("-0x80i8", -128),
+            ("-0b1i16", -1),
+            ("0u8", 0),
+            ("1_000i32", 1000),
+        ];
+        for (text, value) in cases {
+            assert_eq!(parser.parse::<i64>(text), Ok(value), "{text}");
+        }
+        assert_eq!(u8::parse_literal("0xFFu8"), Ok(255));
+        assert_eq!(parser.parse::<u64>("0xFFFF_FFFF_FFFF_FFFFu64"), Ok(u64::MAX));
+        assert_eq!(i128::parse_literal(format!("{}i128", i128::MIN)), Ok(i128::MIN));
+    }
+
+    #[test]
+    fn rust_suffixes_out_of_range_fail() {
+        assert_eq!(u8::parse_literal("0x100u8"), Err(ParseLiteralError::Overflow));
+        assert_eq!(u32::parse_literal("0x100u8"), Err(ParseLiteralError::Overflow));
+        assert_eq!(u8::parse_literal("0xFFu16"), Ok(255));
+        assert_eq!(u8::parse_literal("0x1FFu16"), Err(ParseLiteralError::Overflow));
+        assert_eq!(i32::parse_literal("0x80i8"), Err(ParseLiteralError::Overflow));
+        assert_eq!(i32::parse_literal("-0x81i8"), Err(ParseLiteralError::Overflow));
+        assert_eq!(i32::parse_literal("-1u8"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn rust_suffixes_malformed_fail() {
+        assert_eq!(u8::parse_literal("0xu8"), Err(ParseLiteralError::Empty));
+        assert_eq!(u8::parse_literal("u8"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u8::parse_literal("0xFFu9"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u8::parse_literal("0xFFU8"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u8::parse_literal("0xFFu8u8"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u8::parse_literal("0b12u8"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u64::parse_literal("0zu8"), Ok(36 * 30 + 8));
+        assert_eq!(LiteralParser::new().strict(true).parse::<u8>("5 u8"), Err(ParseLiteralError::InvalidDigit));
+        let parser = LiteralParser::new().rust_suffixes(false);
+        assert_eq!(parser.parse::<u8>("0xFFu8"), Err(ParseLiteralError::InvalidDigit));
+        for parser in [LiteralParser::python(), LiteralParser::go(), LiteralParser::c()] {
+            assert_eq!(parser.parse::<u8>("0xFFu8"), Err(ParseLiteralError::InvalidDigit));
+        }
+    }
+
+    #[test]
+    fn percent_binary_works() {
+        let parser = LiteralParser::new().percent_binary(true);
+        assert_eq!(parser.parse::<u8>("%1010"), Ok(10));
+        assert_eq!(parser.parse::<i8>("-%1_0"), Ok(-2));
+        assert_eq!(parser.parse::<u8>("0b11"), Ok(3));
+        assert_eq!(parser.parse::<u8>("%"), Err(ParseLiteralError::Empty));
+        assert_eq!(parser.parse::<u8>("%102"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u8>("%100000000"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parser.parse::<u8>("25%"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn percent_binary_disabled_by_default() {
+        assert_eq!(LiteralParser::new().parse::<u8>("%1010"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(u8::parse_literal("%1010"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn strict_percent_binary_separator_fails() {
+        let parser = LiteralParser::new().strict(true).percent_binary(true);
+        assert_eq!(parser.parse::<u8>("%_1"), Err(ParseLiteralError::SeparatorAfterPrefix));
+        assert_eq!(parser.parse::<u8>("%1__1"), Err(ParseLiteralError::MisplacedSeparator));
+    }
+
+    #[test]
+    fn trim_works() {
+        let parser = LiteralParser::new();
+        assert_eq!(parser.parse::<u8>("  0xFF  "), Ok(255));
+        assert_eq!(parser.parse::<u8>("\t'A'\n"), Ok(65));
+    }
+
+    #[test]
+    fn no_trim_whitespace_fails() {
+        let parser = LiteralParser::new().trim(false);
+        assert_eq!(parser.parse::<u8>("0xFF"), Ok(255));
+        assert_eq!(parser.parse::<u8>("  0xFF"), Err(ParseLiteralError::LeadingWhitespace));
+        assert_eq!(parser.parse::<u8>("\t-1"), Err(ParseLiteralError::LeadingWhitespace));
+        assert_eq!(parser.parse::<u8>("0xFF "), Err(ParseLiteralError::TrailingWhitespace));
+        assert_eq!(parser.parse::<u8>("'A'\n"), Err(ParseLiteralError::TrailingWhitespace));
+        assert_eq!(parser.parse::<u8>(" "), Err(ParseLiteralError::LeadingWhitespace));
+    }
+
+    #[test]
+    fn exact_type_just_over_limit_fails() {
+        let parser = LiteralParser::new().exact_type(true);
+        let out_of_range = |value: &str| ParseLiteralError::OutOfRange { value: value.to_string() };
+        assert_eq!(parser.parse::<u8>("256").unwrap_err(), out_of_range("256"));
+        assert_eq!(parser.parse::<u8>(" 300 ").unwrap_err(), out_of_range("300"));
+        assert_eq!(parser.parse::<i8>("128").unwrap_err(), out_of_range("128"));
+        assert_eq!(parser.parse::<i8>("-129").unwrap_err(), out_of_range("-129"));
+        assert_eq!(parser.parse::<u16>("0x1_0000").unwrap_err(), out_of_range("0x1_0000"));
+        assert_eq!(parser.parse::<i16>("-32769").unwrap_err(), out_of_range("-32769"));
+        assert_eq!(parser.parse::<u32>("4294967296").unwrap_err(), out_of_range("4294967296"));
+        assert_eq!(parser.parse::<i32>("2147483648").unwrap_err(), out_of_range("2147483648"));
+        assert_eq!(parser.parse::<u64>("18446744073709551616").unwrap_err(), out_of_range("18446744073709551616"));
+        assert_eq!(parser.parse::<i64>("-9223372036854775809").unwrap_err(), out_of_range("-9223372036854775809"));
+        let text = "340282366920938463463374607431768211456";
+        assert_eq!(parser.parse::<u128>(text).unwrap_err(), out_of_range(text));
+    }
+
+    #[test]
+    fn exact_type_limits_work() {
+        let parser = LiteralParser::new().exact_type(true);
+        assert_eq!(parser.parse::<u8>("255"), Ok(u8::MAX));
+        assert_eq!(parser.parse::<u8>("0b0000_0000_1111_1111"), Ok(u8::MAX));
+        assert_eq!(parser.parse::<i8>("-128"), Ok(i8::MIN));
+        assert_eq!(parser.parse::<i16>("-0x8000"), Ok(i16::MIN));
+        assert_eq!(parser.parse::<u128>(&u128::MAX.to_string()), Ok(u128::MAX));
+        assert_eq!(parser.parse::<i128>(&i128::MIN.to_string()), Ok(i128::MIN));
+        assert_eq!(parser.parse::<f64>("1000000000000000000000000000000000000000"), Ok(1e39));
+    }
+
+    #[test]
+    fn exact_type_by_digit_count_works() {
+        assert!(exceeds_width::<u8>(false, 4, 10));
+        assert!(!exceeds_width::<u8>(false, 3, 10));
+        assert!(exceeds_width::<u8>(false, 9, 2));
+        assert!(!exceeds_width::<u8>(false, 8, 2));
+        assert!(!exceeds_width::<i8>(true, 8, 2));
+        assert!(!exceeds_width::<u8>(false, 0, 10));
+        assert_eq!(value_bits::<i32>(), Some(31));
+        assert_eq!(value_bits::<u64>(), Some(64));
+        assert_eq!(value_bits::<NumTarget<f32>>(), None);
+    }
+
+    #[test]
+    fn exact_type_keeps_other_errors() {
+        let parser = LiteralParser::new().exact_type(true);
+        assert_eq!(parser.parse::<u8>("0x1FG"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u8>("-1"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(LiteralParser::new().parse::<u8>("300"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn max_len_works() {
+        let parser = LiteralParser::new().max_len(8);
+        assert_eq!(parser.parse::<u32>("0xCAFE"), Ok(0xCAFE));
+        assert_eq!(parser.parse::<u32>("12345678"), Ok(12_345_678));
+        assert_eq!(parser.parse::<u32>("123456789"), Err(ParseLiteralError::TooLong));
+        assert_eq!(parser.parse::<u32>("   0x1   "), Err(ParseLiteralError::TooLong));
+    }
+
+    #[test]
+    fn max_len_overlong_input_fails() {
+        let text = "9".repeat(10_000_000);
+        assert_eq!(LiteralParser::new().max_len(64).parse::<u128>(&text), Err(ParseLiteralError::TooLong));
+        assert_eq!(LiteralParser::new().max_len(64).parse::<u128>(&text[..38]), Ok(10u128.pow(38) - 1));
+    }
+
+    #[test]
+    fn h_prefix_disabled_fails() {
+        assert_eq!(LiteralParser::new().parse::<u32>("0hFF"), Ok(255));
+        assert_eq!(LiteralParser::new().h_prefix(false).parse::<u32>("0hFF"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(LiteralParser::new().h_prefix(false).parse::<u32>("0xFF"), Ok(255));
+        for parser in [LiteralParser::python(), LiteralParser::go(), LiteralParser::c()] {
+            assert_eq!(parser.parse::<u32>("0hFF"), Err(ParseLiteralError::InvalidDigit));
+        }
+    }
+
+    #[test]
+    fn python_accepts() {
+        let parser = LiteralParser::python();
+        let accepted: [(&str, i64); 10] = [
+            ("0o777", 0o777),
+            ("0O17", 0o17),
+            ("0b1010", 0b1010),
+            ("0x_FF", 0xFF),
+            ("0b_1_0", 0b10),
+            ("1_000", 1000),
+            ("0", 0),
+            ("00", 0),
+            ("0_0", 0),
+            ("-0xff", -0xFF),
+        ];
+        for (text, value) in accepted {
+            assert_eq!(parser.parse::<i64>(text), Ok(value), "{text}");
+        }
+    }
+
+    #[test]
+    fn python_rejects() {
+        let parser = LiteralParser::python();
+        let rejected = [
+            ("0123", ParseLiteralError::LeadingZero),
+            ("007", ParseLiteralError::LeadingZero),
+            ("_1", ParseLiteralError::MisplacedSeparator),
+            ("1_", ParseLiteralError::MisplacedSeparator),
+            ("1__000", ParseLiteralError::MisplacedSeparator),
+            ("0x__FF", ParseLiteralError::MisplacedSeparator),
+            ("0b", ParseLiteralError::Empty),
+            ("0o8", ParseLiteralError::InvalidDigit),
+            ("'A'", ParseLiteralError::InvalidDigit),
+        ];
+        for (text, error) in rejected {
+            assert_eq!(parser.parse::<i64>(text), Err(error), "{text}");
+        }
+    }
+
+    #[test]
+    fn go_accepts() {
+        let parser = LiteralParser::go();
+        let accepted: [(&str, i64); 9] = [
+            ("0o17", 0o17),
+            ("017", 0o17),
+            ("0O_17", 0o17),
+            ("0_600", 0o600),
+            ("0b_1010", 0b1010),
+            ("0xBad_Face", 0xBAD_FACE),
+            ("170_141_183", 170_141_183),
+            ("'a'", 97),
+            ("0", 0),
+        ];
+        for (text, value) in accepted {
+            assert_eq!(parser.parse::<i64>(text), Ok(value), "{text}");
+        }
+    }
+
+    #[test]
+    fn go_rejects() {
+        let parser = LiteralParser::go();
+        let rejected = [
+            ("_42", ParseLiteralError::MisplacedSeparator),
+            ("42_", ParseLiteralError::MisplacedSeparator),
+            ("4__2", ParseLiteralError::MisplacedSeparator),
+            ("0_xBadFace", ParseLiteralError::InvalidDigit),
+            ("0x__1", ParseLiteralError::MisplacedSeparator),
+            ("018", ParseLiteralError::InvalidDigit),
+            ("0x", ParseLiteralError::Empty),
+        ];
+        for (text, error) in rejected {
+            assert_eq!(parser.parse::<i64>(text), Err(error), "{text}");
+        }
+    }
+
+    #[test]
+    fn c_suffixes_work() {
+        let parser = LiteralParser::c();
+        let suffixes = [
+            "", "u", "U", "l", "L", "ll", "LL", "ul", "uL", "Ul", "UL", "lu", "LU",
+            "ull", "ULL", "uLL", "llu", "LLU", "LLu",
+        ];
+        for suffix in suffixes {
+            assert_eq!(parser.parse::<u64>(&format!("42{suffix}")), Ok(42), "{suffix}");
+            assert_eq!(parser.parse::<u64>(&format!("0xFF{suffix}")), Ok(255), "{suffix}");
+        }
+        assert_eq!(parser.parse::<i64>("-017L"), Ok(-15));
+    }
+
+    #[test]
+    fn c_invalid_suffixes_fail() {
+        let parser = LiteralParser::c();
+        for text in ["123UU", "123lL", "123Ll", "123LLL", "123LUL", "123uLLu", "0x1ULU"] {
+            assert_eq!(parser.parse::<u64>(text), Err(ParseLiteralError::InvalidSuffix), "{text}");
+        }
+        assert_eq!(parser.parse::<u64>("L"), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn bigint_suffix_works() {
+        let parser = LiteralParser::new().bigint_suffix(true);
+        assert_eq!(parser.parse::<u128>("123n"), Ok(123));
+        assert_eq!(parser.parse::<u128>("0xFFn"), Ok(255));
+        assert_eq!(parser.parse::<i128>("-0b101n"), Ok(-5));
+        assert_eq!(parser.parse::<u128>("0xFF"), Ok(255));
+        assert_eq!(
+            parser.parse::<u128>("340282366920938463463374607431768211455n"),
+            Ok(u128::MAX),
+        );
+    }
+
+    #[test]
+    fn bigint_suffix_on_float_fails() {
+        let parser = LiteralParser::new().bigint_suffix(true);
+        assert_eq!(parser.parse::<u64>("1.5n"), Err(ParseLiteralError::InvalidSuffix));
+        assert_eq!(parser.parse::<u64>("1e3n"), Err(ParseLiteralError::InvalidSuffix));
+        assert_eq!(parser.parse::<u64>("n"), Err(ParseLiteralError::Empty));
+        assert_eq!(parser.parse::<u64>("12nn"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn integer_exponent_works() {
+        let parser = LiteralParser::new().integer_exponent(true);
+        assert_eq!(parser.parse::<u32>("1e3"), Ok(1000));
+        assert_eq!(parser.parse::<u32>("1.5e1"), Ok(15));
+        assert_eq!(parser.parse::<u32>("150e-1"), Ok(15));
+        assert_eq!(parser.parse::<i32>("-2.50E2"), Ok(-250));
+        assert_eq!(parser.parse::<u32>("0.0e-7"), Ok(0));
+        assert_eq!(parser.parse::<u32>("1_000.0"), Ok(1000));
+        assert_eq!(parser.parse::<u32>("0x1E3"), Ok(0x1E3));
+    }
+
+    #[test]
+    fn integer_exponent_not_integral_fails() {
+        let parser = LiteralParser::new().integer_exponent(true);
+        assert_eq!(parser.parse::<u32>("15e-1"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parser.parse::<u32>("1e-1"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parser.parse::<u32>("1.25e1"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parser.parse::<u32>("_+1.0"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn integer_exponent_overflow_fails() {
+        let parser = LiteralParser::new().integer_exponent(true);
+        assert_eq!(parser.parse::<u8>("3e2"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parser.parse::<u64>("1e99999999999999999999"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn integer_exponent_large_works() {
+        let parser = LiteralParser::new().integer_exponent(true);
+        assert_eq!(parser.parse::<u64>("1e19"), Ok(10_000_000_000_000_000_000));
+        assert_eq!(parser.parse::<u64>("18.446744073709551615e18"), Ok(u64::MAX));
+        assert_eq!(parser.parse::<u128>("3e38"), Ok(3 * 10u128.pow(38)));
+        assert_eq!(parser.parse::<u64>("0e99999"), Ok(0));
+    }
+
+    #[test]
+    fn integer_exponent_large_overflow_fails() {
+        let parser = LiteralParser::new().integer_exponent(true);
+        assert_eq!(parser.parse::<u64>("1e40"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parser.parse::<u64>("1e20"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parser.parse::<u64>("18.446744073709551616e18"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parser.parse::<u128>("4e38"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parser.parse::<i64>("-1e4096"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn integer_exponent_is_off_by_default() {
+        assert_eq!(LiteralParser::new().parse::<u32>("1e3"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn guess_hex_works() {
+        let parser = LiteralParser::new().guess_hex(true);
+        assert_eq!(parser.parse::<u32>("CAFE"), Ok(0xCAFE));
+        assert_eq!(parser.parse::<u32>("dead_beef"), Ok(0xDEAD_BEEF));
+        assert_eq!(parser.parse::<u32>("00FF"), Ok(0xFF));
+        assert_eq!(parser.parse::<i32>("-1a"), Ok(-0x1A));
+    }
+
+    #[test]
+    fn guess_hex_keeps_valid_literals() {
+        let parser = LiteralParser::new().guess_hex(true);
+        assert_eq!(parser.parse::<u32>("123"), Ok(123));
+        assert_eq!(parser.parse::<u32>("0b10"), Ok(2));
+        assert_eq!(parser.parse::<u32>("0123"), Ok(0o123));
+        assert_eq!(parser.parse::<u32>("CAFEG"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(LiteralParser::new().parse::<u32>("CAFE"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn guess_hex_skips_prefixed_literals() {
+        let parser = LiteralParser::new().guess_hex(true);
+        assert_eq!(parser.parse::<u32>("0b1F"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u32>("0b12"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u32>("0o8A"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<i32>("-0B1F"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u32>("1e3"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.percent_binary(true).parse::<u32>("%1F"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn inline_radix_works() {
+        let parser = LiteralParser::new().inline_radix(true);
+        assert_eq!(parser.parse::<u32>("7:123"), Ok(66));
+        assert_eq!(parser.parse::<u32>("3:2_1"), Ok(7));
+        assert_eq!(parser.parse::<u32>("36:zZ"), Ok(1295));
+        assert_eq!(parser.parse::<i32>("-12:b0"), Ok(-132));
+        assert_eq!(parser.parse::<u32>("02:101"), Ok(5));
+        assert_eq!(parser.parse::<u32>("0x10"), Ok(16));
+    }
+
+    #[test]
+    fn inline_radix_fails() {
+        let parser = LiteralParser::new().inline_radix(true);
+        assert_eq!(parser.parse::<u32>("1:0"), Err(ParseLiteralError::InvalidRadix));
+        assert_eq!(parser.parse::<u32>("37:1"), Err(ParseLiteralError::InvalidRadix));
+        assert_eq!(parser.parse::<u32>("99999999999:1"), Err(ParseLiteralError::InvalidRadix));
+        assert_eq!(parser.parse::<u32>("7:"), Err(ParseLiteralError::Empty));
+        assert_eq!(parser.parse::<u32>("7:128"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parser.parse::<u32>("hex:FF"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(LiteralParser::new().parse::<u32>("7:123"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn presets_reject_base36() {
+        for parser in [LiteralParser::python(), LiteralParser::go(), LiteralParser::c()] {
+            assert_eq!(parser.parse::<u32>("0zZ9"), Err(ParseLiteralError::InvalidDigit));
+        }
+    }
+
+    #[test]
+    fn suffixes_are_off_by_default() {
+        assert_eq!(LiteralParser::new().parse::<u64>("42UL"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(LiteralParser::new().parse::<u64>("42n"), Err(ParseLiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn lenient_separator_after_prefix_works() {
+        let parser = LiteralParser::new();
+        assert_eq!(parser.parse::<u32>("0x_FF"), Ok(255));
+        assert_eq!(parser.parse::<u32>("0b_1010"), Ok(10));
+    }
+}
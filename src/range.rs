@@ -0,0 +1,83 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::{NumLiteralTrait, ParseLiteralError};
+
+/// Split a Rust-style range like `1..10` or `0x0..=0xFF` and convert both
+/// bounds as literals, or return an error.
+///
+/// A text without `..` is an `InvalidRange` error, and a missing bound
+/// is an `Empty` error. With `strict`, a start greater than the end is an
+/// `InvertedRange` error; an empty range like `5..5` is allowed.
+///
+/// # Arguments
+/// - `text`: Start and end literals, split by `..` or `..=`.
+/// - `strict`: Whether to reject a start greater than the end.
+/// # Returns
+/// - The start, end and whether the range is inclusive, or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_range_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_range_literal::<u8>("1..10", true), Ok((1, 10, false)));
+/// assert_eq!(parse_range_literal::<u8>("0x0..=0xFF", true), Ok((0, 255, true)));
+/// assert_eq!(parse_range_literal::<u8>("10..1", true), Err(ParseLiteralError::InvertedRange));
+/// assert_eq!(parse_range_literal::<u8>("10..1", false), Ok((10, 1, false)));
+/// ```
+pub fn parse_range_literal<T: Num + PartialOrd>(text: &str, strict: bool) -> Result<(T, T, bool), ParseLiteralError> {
+    let (start, end) = text.split_once("..").ok_or(ParseLiteralError::InvalidRange)?;
+    let (end, inclusive) = match end.strip_prefix('=') {
+        Some(end) => (end, true),
+        None => (end, false),
+    };
+    let start = T::parse_literal(start)?;
+    let end = T::parse_literal(end)?;
+    if strict && start > end {
+        return Err(ParseLiteralError::InvertedRange);
+    }
+    Ok((start, end, inclusive))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_exclusive_works() {
+        assert_eq!(parse_range_literal::<u8>("1..10", true), Ok((1, 10, false)));
+        assert_eq!(parse_range_literal::<u16>("0x10..0b1_0000_0000", true), Ok((16, 256, false)));
+        assert_eq!(parse_range_literal::<i8>(" -0o10 .. 'A' ", true), Ok((-8, 65, false)));
+        assert_eq!(parse_range_literal::<u8>("5..5", true), Ok((5, 5, false)));
+    }
+
+    #[test]
+    fn range_inclusive_works() {
+        assert_eq!(parse_range_literal::<u8>("0x0..=0xFF", true), Ok((0, 255, true)));
+        assert_eq!(parse_range_literal::<u32>("0b1..=1_000", true), Ok((1, 1000, true)));
+        assert_eq!(parse_range_literal::<i32>("-0x10 ..= 0o20", true), Ok((-16, 16, true)));
+    }
+
+    #[test]
+    fn range_inverted_fails_when_strict() {
+        assert_eq!(parse_range_literal::<u8>("0x10..1", true), Err(ParseLiteralError::InvertedRange));
+        assert_eq!(parse_range_literal::<u8>("0x10..=1", true), Err(ParseLiteralError::InvertedRange));
+        assert_eq!(parse_range_literal::<u8>("0x10..=1", false), Ok((16, 1, true)));
+    }
+
+    #[test]
+    fn range_malformed_fails() {
+        assert_eq!(parse_range_literal::<u8>("1-10", true), Err(ParseLiteralError::InvalidRange));
+        assert_eq!(parse_range_literal::<u8>("..10", true), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_range_literal::<u8>("1..", true), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_range_literal::<u8>("1...10", true), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_range_literal::<u8>("1..=256", true), Err(ParseLiteralError::Overflow));
+    }
+}
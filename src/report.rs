@@ -0,0 +1,190 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::Range;
+use crate::{detect_format, literal_warnings, LiteralFormat, LiteralSign, LiteralWarning, ParseLiteralError};
+use crate::constant::strip_rust_suffix;
+use crate::format::match_prefix;
+use crate::parse_literal::{check_digits, split_sign};
+
+/// Everything found out about a literal without converting it, returned
+/// by `analyze_literal`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LiteralReport {
+    /// The format the literal is written in, judged from its prefix.
+    pub format: LiteralFormat,
+    /// The sign as written.
+    pub sign: LiteralSign,
+    /// The radix of the digits, or `None` for char literals.
+    pub radix: Option<u32>,
+    /// The byte range of the radix prefix in the input, empty at the
+    /// start of the digits if there is none.
+    pub prefix: Range<usize>,
+    /// The byte range of a Rust type suffix like `u8` in the input, empty
+    /// at the end of the literal if there is none.
+    pub suffix: Range<usize>,
+    /// The digits without separators, in lowercase, or the character code
+    /// in decimal for char literals.
+    pub digits: String,
+    /// The warnings of `literal_warnings`.
+    pub warnings: Vec<LiteralWarning>,
+    /// Why the text is not a literal, or `None` if it is one.
+    pub error: Option<ParseLiteralError>,
+}
+
+impl LiteralReport {
+    /// Whether the text is a well-formed literal.
+    pub fn is_literal(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Inspect a literal as `parse_literal` would read it and report what was
+/// found, without converting it.
+///
+/// This never fails: a text that is not a literal, like `"hello"`, still
+/// gets a report, with the reason in `error`. As no target type is given,
+/// values too large for a type are not detected, and floats are reported
+/// as malformed integers. The spans are byte offsets into `text`,
+/// including surrounding whitespace, for highlighting in editors.
+///
+/// # Arguments
+/// - `text`: Textual representation of a number.
+/// # Returns
+/// - The report.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{analyze_literal, LiteralFormat, LiteralSign, ParseLiteralError};
+///
+/// let report = analyze_literal("-0xFF_FFu16");
+/// assert_eq!(report.format, LiteralFormat::Hexadecimal);
+/// assert_eq!(report.sign, LiteralSign::Minus);
+/// assert!(report.is_literal());
+/// assert_eq!(report.digits, "ffff");
+/// assert_eq!((report.prefix, report.suffix), (1..3, 8..11));
+/// assert_eq!(analyze_literal("hello").error, Some(ParseLiteralError::InvalidDigit));
+/// ```
+pub fn analyze_literal(text: &str) -> LiteralReport {
+    let leading = text.len() - text.trim_start().len();
+    let trimmed = text.trim();
+    let end = leading + trimmed.len();
+    if let [b'\'', chr, b'\''] = trimmed.as_bytes() {
+        return LiteralReport {
+            format: LiteralFormat::Char,
+            sign: LiteralSign::Implicit,
+            radix: None,
+            prefix: leading..leading,
+            suffix: end..end,
+            digits: chr.to_string(),
+            warnings: Vec::new(),
+            error: None,
+        };
+    }
+    let sign = match trimmed.as_bytes().first() {
+        Some(b'+') => LiteralSign::ExplicitPlus,
+        Some(b'-') => LiteralSign::Minus,
+        _ => LiteralSign::Implicit,
+    };
+    let sign_error = split_sign(trimmed).err();
+    let unsigned = &trimmed[usize::from(sign != LiteralSign::Implicit)..];
+    let body = strip_rust_suffix(unsigned).0;
+    let format = detect_format(body);
+    let prefix_len = match match_prefix(body) {
+        Some((prefix, _)) => prefix.len(),
+        None => usize::from(format == LiteralFormat::Octal),
+    };
+    let radix = format.radix().unwrap_or(10);
+    let digits = body[prefix_len..].replace("_", "").to_ascii_lowercase();
+    let start = end - unsigned.len();
+    let body_end = start + body.len();
+    LiteralReport {
+        format,
+        sign,
+        radix: Some(radix),
+        prefix: start..start + prefix_len,
+        suffix: body_end..end,
+        error: sign_error.or_else(|| check_digits(&digits, radix).err()),
+        digits,
+        warnings: literal_warnings(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NumLiteralTrait;
+
+    #[test]
+    fn report_fields_work() {
+        let report = analyze_literal("  0xFFu8 ");
+        assert_eq!(report.format, LiteralFormat::Hexadecimal);
+        assert_eq!(report.sign, LiteralSign::Implicit);
+        assert_eq!(report.radix, Some(16));
+        assert_eq!((report.prefix, report.suffix), (2..4, 6..8));
+        assert_eq!(report.digits, "ff");
+        assert_eq!(report.warnings, vec![]);
+        assert_eq!(report.error, None);
+
+        let report = analyze_literal("+0b1010_0101");
+        assert_eq!((report.format, report.radix), (LiteralFormat::Binary, Some(2)));
+        assert_eq!(report.sign, LiteralSign::ExplicitPlus);
+        assert_eq!((report.prefix, report.suffix), (1..3, 12..12));
+        assert_eq!(report.digits, "10100101");
+
+        let report = analyze_literal("-1_000");
+        assert_eq!((report.format, report.sign, report.radix), (LiteralFormat::Decimal, LiteralSign::Minus, Some(10)));
+        assert!(report.is_literal());
+        assert_eq!((report.prefix, report.digits.as_str()), (1..1, "1000"));
+    }
+
+    #[test]
+    fn report_octal_warns() {
+        let report = analyze_literal(" -0755 ");
+        assert_eq!((report.format, report.radix), (LiteralFormat::Octal, Some(8)));
+        assert_eq!((report.prefix, report.suffix, report.digits.as_str()), (2..3, 6..6, "755"));
+        assert_eq!(report.warnings, vec![LiteralWarning::AmbiguousOctal]);
+        assert_eq!(analyze_literal("0123u32").warnings, vec![LiteralWarning::AmbiguousOctal]);
+        assert_eq!(analyze_literal("0o123").warnings, vec![]);
+    }
+
+    #[test]
+    fn report_char_works() {
+        let report = analyze_literal(" 'A' ");
+        assert_eq!((report.format, report.radix, report.digits.as_str()), (LiteralFormat::Char, None, "65"));
+        assert!(report.is_literal());
+        assert_eq!((report.prefix, report.suffix), (1..1, 4..4));
+    }
+
+    #[test]
+    fn report_not_a_literal_works() {
+        let report = analyze_literal("hello");
+        assert_eq!((report.format, report.digits.as_str()), (LiteralFormat::Decimal, "hello"));
+        assert_eq!(report.error, Some(ParseLiteralError::InvalidDigit));
+        assert!(!report.is_literal());
+        assert_eq!(analyze_literal("").error, Some(ParseLiteralError::Empty));
+        assert_eq!(analyze_literal("-").error, Some(ParseLiteralError::LoneSign));
+        assert_eq!(analyze_literal("0x").error, Some(ParseLiteralError::Empty));
+        assert_eq!(analyze_literal("1.5").error, Some(ParseLiteralError::InvalidDigit));
+        let report = analyze_literal("0zu8");
+        assert_eq!((report.format, report.suffix, report.digits.as_str()), (LiteralFormat::Base36, 4..4, "u8"));
+    }
+
+    #[test]
+    fn report_error_matches_parse_literal() {
+        let texts = [
+            "0", "00", "123", " 42 ", "+7", "-7", "0xCAFE", "0b1010", "0o777", "0755", "0zZ9", "'A'", "1_000", "0x",
+            "", "-", "+-1", "- 1", "0x-1", "CAFE", "08", "0b2", "__", "0hFF", "0xFFu8", "-1i8", "0u8", "u8", "-'A'",
+        ];
+        for text in texts {
+            assert_eq!(analyze_literal(text).error, i128::parse_literal(text).err(), "{text:?}");
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{NumLiteralTrait, ParseLiteralError};
+
+/// RKM code letters and the power of ten they scale by.
+const LETTERS: [(char, u32); 5] = [('R', 0), ('k', 3), ('K', 3), ('M', 6), ('G', 9)];
+
+/// Convert a value in RKM code, as printed on resistors, to a number
+/// value or return an error.
+///
+/// The letter `R`, `k` (or `K`), `M` or `G` takes the place of the
+/// decimal point and scales the value by 1, 10³, 10⁶ or 10⁹, so `4k7` is
+/// 4700 and `R47` is 0.47. The digits around the letter are decimal. A
+/// text without a letter is read as a plain number like in
+/// `parse_float_literal`, while a second letter or any other character
+/// is an `InvalidDigit` error.
+///
+/// # Arguments
+/// - `text`: Value in RKM code.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_rkm;
+///
+/// assert_eq!(parse_rkm("4k7"), Ok(4700.0));
+/// assert_eq!(parse_rkm("1R0"), Ok(1.0));
+/// assert_eq!(parse_rkm("470"), Ok(470.0));
+/// ```
+pub fn parse_rkm(text: &str) -> Result<f64, ParseLiteralError> {
+    let text = text.trim();
+    let Some((index, exponent)) = text.char_indices().find_map(|(index, chr)| {
+        LETTERS.into_iter().find(|&(letter, _)| letter == chr).map(|(_, exponent)| (index, exponent))
+    }) else {
+        return f64::parse_float_literal(text);
+    };
+    let (int_part, frac_part) = (&text[..index], &text[index + 1..]);
+    if text.starts_with(['+', '-']) {
+        return Err(ParseLiteralError::InvalidSign);
+    }
+    if !text.bytes().enumerate().all(|(i, byte)| i == index || byte.is_ascii_digit()) {
+        return Err(ParseLiteralError::InvalidDigit);
+    }
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    f64::parse_float_literal(format!("0{int_part}.{frac_part}0e{exponent}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rkm_r_works() {
+        assert_eq!(parse_rkm("1R0"), Ok(1.0));
+        assert_eq!(parse_rkm("R47"), Ok(0.47));
+        assert_eq!(parse_rkm("470R"), Ok(470.0));
+        assert_eq!(parse_rkm("2R2"), Ok(2.2));
+    }
+
+    #[test]
+    fn rkm_k_works() {
+        assert_eq!(parse_rkm("4k7"), Ok(4700.0));
+        assert_eq!(parse_rkm("4K7"), Ok(4700.0));
+        assert_eq!(parse_rkm("10k"), Ok(10_000.0));
+        assert_eq!(parse_rkm("k47"), Ok(470.0));
+    }
+
+    #[test]
+    fn rkm_m_works() {
+        assert_eq!(parse_rkm("2M2"), Ok(2_200_000.0));
+        assert_eq!(parse_rkm("1M"), Ok(1_000_000.0));
+    }
+
+    #[test]
+    fn rkm_g_works() {
+        assert_eq!(parse_rkm("1G5"), Ok(1_500_000_000.0));
+        assert_eq!(parse_rkm("G1"), Ok(100_000_000.0));
+    }
+
+    #[test]
+    fn rkm_plain_number_works() {
+        assert_eq!(parse_rkm(" 470 "), Ok(470.0));
+        assert_eq!(parse_rkm("4.7"), Ok(4.7));
+    }
+
+    #[test]
+    fn rkm_malformed_fails() {
+        assert_eq!(parse_rkm("4k7k"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_rkm("4.7k"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_rkm("4x7"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_rkm("-4k7"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(parse_rkm("R"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_rkm(""), Err(ParseLiteralError::Empty));
+    }
+}
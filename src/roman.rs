@@ -0,0 +1,147 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::ParseLiteralError;
+use crate::parse_literal::from_digits;
+
+/// Roman numeral symbols and subtractive pairs with their values, largest
+/// first, as used to write a number in standard form.
+const NUMERALS: [(&str, u32); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+/// The value of a single Roman numeral symbol.
+fn symbol_value(symbol: u8) -> Option<u32> {
+    NUMERALS.into_iter()
+        .find(|(numeral, _)| numeral.as_bytes() == [symbol.to_ascii_uppercase()])
+        .map(|(_, value)| value)
+}
+
+/// Write a number from 1 to 3999 as a Roman numeral in standard form.
+fn to_roman(mut value: u32) -> String {
+    let mut roman = String::new();
+    for (numeral, numeral_value) in NUMERALS {
+        while value >= numeral_value {
+            roman.push_str(numeral);
+            value -= numeral_value;
+        }
+    }
+    roman
+}
+
+/// Convert a Roman numeral like `XIV` to a number value or return an
+/// error.
+///
+/// The symbols `I`, `V`, `X`, `L`, `C`, `D` and `M` are read in either
+/// case, and a symbol before a larger one is subtracted, so `XIV` and
+/// `xiv` are 14. Any other character is an `InvalidDigit` error. When
+/// `strict` is set, the numeral must be in the standard form from 1 to
+/// 3999, so `IIII`, `IC` or `MMMM` are an `InvalidNumeral` error, while
+/// otherwise they are read as 4, 99 and 4000.
+///
+/// # Arguments
+/// - `text`: Roman numeral.
+/// - `strict`: Whether to only accept numerals in standard form.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_roman, ParseLiteralError};
+///
+/// assert_eq!(parse_roman::<u32>("XIV", true), Ok(14));
+/// assert_eq!(parse_roman::<u32>("mcmxciv", true), Ok(1994));
+/// assert_eq!(parse_roman::<u32>("IIII", false), Ok(4));
+/// assert_eq!(parse_roman::<u32>("IIII", true), Err(ParseLiteralError::InvalidNumeral));
+/// ```
+pub fn parse_roman<T: Num>(text: &str, strict: bool) -> Result<T, ParseLiteralError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    let values = text.bytes().map(symbol_value).collect::<Option<Vec<_>>>()
+        .ok_or(ParseLiteralError::InvalidDigit)?;
+    // A subtracted symbol is always followed by a larger one, so the
+    // total only drops below zero in between.
+    let mut total = 0i128;
+    for (i, &value) in values.iter().enumerate() {
+        if values.get(i + 1).is_some_and(|&next| value < next) {
+            total -= i128::from(value);
+        } else {
+            total += i128::from(value);
+        }
+    }
+    if strict && !((1..4000).contains(&total) && to_roman(total as u32).eq_ignore_ascii_case(text)) {
+        return Err(ParseLiteralError::InvalidNumeral);
+    }
+    from_digits(false, &total.to_string(), 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roman_works() {
+        let cases = [
+            ("I", 1), ("IV", 4), ("IX", 9), ("XIV", 14), ("XL", 40), ("XC", 90), ("CD", 400), ("CM", 900),
+            ("MCMXCIV", 1994), ("MMXXV", 2025), ("MMMCMXCIX", 3999), (" xlii ", 42), ("mCmXcIv", 1994),
+        ];
+        for (text, value) in cases {
+            assert_eq!(parse_roman::<u32>(text, true), Ok(value), "{text}");
+            assert_eq!(parse_roman::<u32>(text, false), Ok(value), "{text}");
+        }
+    }
+
+    #[test]
+    fn roman_round_trip_works() {
+        for value in 1..4000 {
+            assert_eq!(parse_roman::<u32>(&to_roman(value), true), Ok(value));
+        }
+    }
+
+    #[test]
+    fn roman_lenient_works() {
+        assert_eq!(parse_roman::<u32>("IIII", false), Ok(4));
+        assert_eq!(parse_roman::<u32>("IC", false), Ok(99));
+        assert_eq!(parse_roman::<u32>("MMMM", false), Ok(4000));
+        assert_eq!(parse_roman::<u32>("VV", false), Ok(10));
+    }
+
+    #[test]
+    fn roman_malformed_fails() {
+        for text in ["IIII", "IC", "VV", "XXXX", "IIV", "MMMM", "LC", "IXI"] {
+            assert_eq!(parse_roman::<u32>(text, true), Err(ParseLiteralError::InvalidNumeral), "{text}");
+        }
+        assert_eq!(parse_roman::<u32>("XIZ", false), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_roman::<u32>("X I", false), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_roman::<u32>("-X", false), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_roman::<u32>("", false), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn roman_overflow_fails() {
+        assert_eq!(parse_roman::<u8>("CCLV", true), Ok(255));
+        assert_eq!(parse_roman::<u8>("CCLVI", true), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_roman::<i8>("CXXVIII", true), Err(ParseLiteralError::Overflow));
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::num::Saturating;
+use crate::{parse_literal_into, LiteralTarget, ParseLiteralError};
+
+/// Convert a literal to a `std::num::Saturating` number value or return
+/// an error.
+///
+/// `Saturating<T>` does not implement `num_traits::Num`, so it has no
+/// `parse_literal` of its own. This is `parse_literal_into` for it, and
+/// like its arithmetic, the conversion saturates: values beyond the range
+/// of `T` are clamped as in `parse_literal_saturating`. A type suffix
+/// still limits the value, so `0x1FFu8` is an `Overflow`.
+///
+/// # Arguments
+/// - `text`: Textual representation of a number.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::num::Saturating;
+/// use num_literal_traits::parse_saturating_literal;
+///
+/// assert_eq!(parse_saturating_literal::<u8>("0x1FF"), Ok(Saturating(255)));
+/// assert_eq!(parse_saturating_literal::<u8>("0x1F"), Ok(Saturating(31)));
+/// ```
+pub fn parse_saturating_literal<T>(text: &str) -> Result<Saturating<T>, ParseLiteralError>
+where
+    Saturating<T>: LiteralTarget,
+{
+    parse_literal_into(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saturating_in_range_works() {
+        assert_eq!(parse_saturating_literal::<u8>("'A'"), Ok(Saturating(65)));
+        assert_eq!(parse_saturating_literal::<i16>("-0x10"), Ok(Saturating(-16)));
+    }
+
+    #[test]
+    fn saturating_overflow_saturates() {
+        assert_eq!(parse_saturating_literal::<u8>("0x1FF"), Ok(Saturating(u8::MAX)));
+        assert_eq!(parse_saturating_literal::<i8>("128"), Ok(Saturating(i8::MAX)));
+        assert_eq!(parse_saturating_literal::<i8>(" -0b1000_0001"), Ok(Saturating(i8::MIN)));
+        assert_eq!(parse_saturating_literal::<u128>(&format!("{}0", u128::MAX)), Ok(Saturating(u128::MAX)));
+        assert_eq!(parse_saturating_literal::<i64>(&format!("{}0", i64::MIN)), Ok(Saturating(i64::MIN)));
+    }
+
+    #[test]
+    fn saturating_suffix_limits_value() {
+        assert_eq!(parse_saturating_literal::<u8>("0x1FFu16"), Ok(Saturating(u8::MAX)));
+        assert_eq!(parse_saturating_literal::<u16>("0x1FFu8"), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn saturating_invalid_fails() {
+        assert_eq!(parse_saturating_literal::<u8>("0x1FG"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_saturating_literal::<u8>("-1"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(parse_saturating_literal::<u8>(""), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn saturating_arithmetic_works() {
+        let value = parse_saturating_literal::<u8>("0xF0").unwrap() + Saturating(0x20);
+        assert_eq!(value, Saturating(u8::MAX));
+    }
+}
@@ -0,0 +1,450 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::{NumLiteralTrait, ParseLiteralError};
+use crate::format::match_prefix;
+use crate::parse_literal::{from_digits, split_sign};
+
+/// Scale suffixes and their multipliers, IEC binary prefixes first so
+/// that `Ki` is not taken for `K`.
+const SCALES: [(&str, u128); 13] = [
+    ("Ki", 1 << 10),
+    ("Mi", 1 << 20),
+    ("Gi", 1 << 30),
+    ("Ti", 1 << 40),
+    ("Pi", 1 << 50),
+    ("Ei", 1 << 60),
+    ("k", 1_000),
+    ("K", 1_000),
+    ("M", 1_000_000),
+    ("G", 1_000_000_000),
+    ("T", 1_000_000_000_000),
+    ("P", 1_000_000_000_000_000),
+    ("E", 1_000_000_000_000_000_000),
+];
+
+/// Split a scale suffix off the end of a text, unless the text has a
+/// radix prefix, where letters like `E` are digits, and return its
+/// multiplier and the decimal places it shifts.
+fn split_scale(text: &str) -> Option<(&str, u128, usize)> {
+    if match_prefix(text.trim_start_matches(['+', '-'])).is_some() {
+        return None;
+    }
+    let multiple = SCALES.into_iter().find_map(|(suffix, multiplier)| {
+        text.strip_suffix(suffix).map(|mantissa| (mantissa, multiplier, 0))
+    });
+    multiple.or_else(|| text.strip_suffix('m').map(|mantissa| (mantissa, 1, 3)))
+}
+
+/// Convert a number with an SI or IEC scale suffix, like `4K` or `1.5Mi`,
+/// to a number value or return an error.
+///
+/// The SI suffixes `k` (or `K`), `M`, `G`, `T`, `P` and `E` scale by
+/// powers of 1000, `m` divides by 1000, and the IEC suffixes `Ki`, `Mi`,
+/// `Gi`, `Ti`, `Pi` and `Ei` scale by powers of 1024. Unlike the radix
+/// prefixes, which are matched in any case, the suffixes are
+/// case-sensitive as in the standards, since case tells them apart:
+/// `5m` is 0.005, while `5M` is 5000000. Only kilo is also accepted as
+/// the customary `K`.
+///
+/// The number before the suffix is decimal and may have a fraction, like
+/// `1.5K`. Scaling is exact, nothing is rounded: `1.5Ki` is 1536, while
+/// `1.0005K` is 1000.5, which is a `NotIntegral` error for integer
+/// types. The scaled value has to fit into 128 bits. Without a suffix,
+/// or with a radix prefix like in `0x1E`, the text is parsed like
+/// `parse_literal`.
+///
+/// # Arguments
+/// - `text`: Textual representation of a scaled number.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_scaled_literal, ParseLiteralError};
+///
+/// assert_eq!(parse_scaled_literal::<u32>("4K"), Ok(4000));
+/// assert_eq!(parse_scaled_literal::<u32>("1.5K"), Ok(1500));
+/// assert_eq!(parse_scaled_literal::<u32>("1.5Ki"), Ok(1536));
+/// assert_eq!(parse_scaled_literal::<u32>("0x10"), Ok(16));
+/// assert_eq!(parse_scaled_literal::<u32>("1.0005K"), Err(ParseLiteralError::NotIntegral));
+/// assert_eq!(parse_scaled_literal::<f64>("1.0005K"), Ok(1000.5));
+/// assert_eq!(parse_scaled_literal::<f64>("5m"), Ok(0.005));
+/// ```
+pub fn parse_scaled_literal<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let text = text.trim();
+    match split_scale(text) {
+        Some((mantissa, multiplier, places)) => scale(mantissa, multiplier, places),
+        None => T::parse_literal(text),
+    }
+}
+
+/// Convert a number with a scale suffix from a caller-provided table, like
+/// `4KiB` with `("KiB", 1024)`, to a number value or return an error.
+///
+/// The suffix is matched case-sensitively at the end of the text, the
+/// longest matching suffix first, whatever the order of the table, so
+/// `"mB"` is not taken for `"B"`. The number before it may be followed by
+/// spaces and is scaled exactly like in `parse_scaled_literal`. Without a
+/// matching suffix, or with a radix prefix like in `0x1E`, the text is
+/// parsed like `parse_literal`.
+///
+/// # Arguments
+/// - `text`: Textual representation of a scaled number.
+/// - `table`: Suffixes and their multipliers.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_with_scale_table;
+///
+/// let table = [("KiB", 1024), ("MiB", 1024 * 1024), ("B", 1)];
+/// assert_eq!(parse_with_scale_table::<u32>("4KiB", &table), Ok(4096));
+/// assert_eq!(parse_with_scale_table::<u32>("1.5 MiB", &table), Ok(1572864));
+/// assert_eq!(parse_with_scale_table::<u32>("512B", &table), Ok(512));
+/// ```
+pub fn parse_with_scale_table<T: Num>(text: &str, table: &[(&str, u128)]) -> Result<T, ParseLiteralError> {
+    let text = text.trim();
+    if match_prefix(text.trim_start_matches(['+', '-'])).is_some() {
+        return T::parse_literal(text);
+    }
+    let suffix = table.iter()
+        .filter(|(suffix, _)| !suffix.is_empty() && text.ends_with(suffix))
+        .max_by_key(|(suffix, _)| suffix.len());
+    match suffix {
+        Some((suffix, multiplier)) => scale(text[..text.len() - suffix.len()].trim_end(), *multiplier, 0),
+        None => T::parse_literal(text),
+    }
+}
+
+/// Prefixes of units that divide by a power of ten and its exponent. The
+/// micro sign `µ` and the Greek letter `μ` look the same, so both are
+/// accepted, and `u` is the ASCII stand-in.
+const SUBMULTIPLES: [(&str, usize); 3] = [("µ", 6), ("μ", 6), ("u", 6)];
+
+/// Split a scale prefix off the start of a unit, with an optional byte
+/// unit `B` as in `KiB` after a multiple, and return its multiplier and
+/// the decimal places it shifts. A digit right after the prefix means it
+/// is not one, like the exponent in `1E5`.
+fn split_scale_prefix(unit: &str) -> Option<(u128, usize, &str)> {
+    let not_exponent = |rest: &str| !rest.starts_with(|c: char| c.is_ascii_digit());
+    let multiple = SCALES.into_iter().find_map(|(prefix, multiplier)| {
+        let rest = unit.strip_prefix(prefix)?;
+        let rest = rest.strip_prefix('B').unwrap_or(rest);
+        not_exponent(rest).then_some((multiplier, 0, rest))
+    });
+    multiple.or_else(|| SUBMULTIPLES.into_iter().find_map(|(prefix, places)| {
+        let rest = unit.strip_prefix(prefix)?;
+        not_exponent(rest).then_some((1, places, rest))
+    }))
+}
+
+/// Length in bytes of the decimal mantissa, like `-1.5`, at the start of
+/// `text`.
+fn mantissa_len(text: &str) -> usize {
+    let sign_len = usize::from(text.starts_with(['+', '-']));
+    let digits_len = text[sign_len..].bytes()
+        .take_while(|&b| b == b'_' || b == b'.' || b.is_ascii_digit())
+        .count();
+    sign_len + digits_len
+}
+
+/// Convert the scaled number at the start of a text to a number value
+/// and return it with the unit after it, or return an error.
+///
+/// A scale suffix of `parse_scaled_literal` directly after a decimal
+/// number is applied to it, together with a `B` for bytes, so `512KB/s`
+/// is 512000 with the unit `/s`. Micro is also accepted as `µ`, `μ` or
+/// `u`, so `4.7µF` is 0.0000047 with the unit `F`, which is a
+/// `NotIntegral` error for integer types. Milli is not a scale here, as
+/// `5m` is far more likely meters. The scale has to follow the number
+/// without a space: `512 KB` is 512 with the unit `KB`. Without a scale,
+/// the text is split like `parse_value_unit`.
+///
+/// A scale letter is always read as a scale, even where it starts a unit:
+/// `5Pa` is 5 peta with the unit `a`, not 5 pascal, and `300K` is 300000
+/// without a unit, not 300 kelvin. Write such units after a space, like
+/// `5 Pa`, or use `parse_value_unit`.
+///
+/// # Arguments
+/// - `text`: Textual representation of a scaled number and unit.
+/// # Returns
+/// - Numerical result and the unit, or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::parse_scaled_value_unit;
+///
+/// assert_eq!(parse_scaled_value_unit::<u32>("512KB/s"), Ok((512000, "/s")));
+/// assert_eq!(parse_scaled_value_unit::<u32>("2Mi/1"), Ok((2097152, "/1")));
+/// assert_eq!(parse_scaled_value_unit::<u32>("512 KB/s"), Ok((512, "KB/s")));
+/// assert_eq!(parse_scaled_value_unit::<f64>("1.5kΩ"), Ok((1500.0, "Ω")));
+/// assert_eq!(parse_scaled_value_unit::<f64>("4.7µF"), Ok((0.0000047, "F")));
+/// ```
+pub fn parse_scaled_value_unit<T: Num>(text: &str) -> Result<(T, &str), ParseLiteralError> {
+    let text = text.trim();
+    let len = mantissa_len(text);
+    match split_scale_prefix(&text[len..]) {
+        Some((multiplier, places, unit)) => Ok((scale(&text[..len], multiplier, places)?, unit.trim_start())),
+        None => T::parse_value_unit(text),
+    }
+}
+
+/// Convert a percentage like `2.5%` to basis points, a hundredth of a
+/// percent, or return an error.
+///
+/// The number before the `%` is decimal and may have a sign and a
+/// fraction. Like in `parse_scaled_literal`, scaling is exact: `2.5%` is
+/// 250, while `2.555%` is 255.5, which is a `NotIntegral` error for
+/// integer types. A missing `%` is an `InvalidUnit` error.
+///
+/// # Arguments
+/// - `text`: Decimal percentage ending with `%`.
+/// # Returns
+/// - Numerical result in basis points or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_basis_points, ParseLiteralError};
+///
+/// assert_eq!(parse_basis_points::<u32>("2.5%"), Ok(250));
+/// assert_eq!(parse_basis_points::<u32>("100%"), Ok(10_000));
+/// assert_eq!(parse_basis_points::<u32>("2.555%"), Err(ParseLiteralError::NotIntegral));
+/// assert_eq!(parse_basis_points::<f64>("2.555%"), Ok(255.5));
+/// ```
+pub fn parse_basis_points<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let mantissa = text.trim().strip_suffix('%').ok_or(ParseLiteralError::InvalidUnit)?;
+    scale(mantissa.trim_end(), 100, 0)
+}
+
+/// Multiply a decimal mantissa like `-1.5` exactly by a multiplier and
+/// divide it by `10^places`.
+fn scale<T: Num>(mantissa: &str, multiplier: u128, places: usize) -> Result<T, ParseLiteralError> {
+    let (negative, mantissa) = split_sign(mantissa)?;
+    let mantissa = mantissa.replace("_", "");
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((&mantissa, ""));
+    let fraction = fraction.trim_end_matches('0');
+    let digits = format!("{whole}{fraction}");
+    if digits.is_empty() {
+        return Err(ParseLiteralError::Empty);
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseLiteralError::InvalidDigit);
+    }
+    let product = digits.parse::<u128>().ok()
+        .and_then(|value| value.checked_mul(multiplier))
+        .ok_or(ParseLiteralError::Overflow)?;
+    let point = fraction.len() + places;
+    let product = format!("{product:0>width$}", width = point + 1);
+    let (whole, fraction) = product.split_at(product.len() - point);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        return from_digits(negative, whole, 10);
+    }
+    if T::from_str_radix("0.5", 10).is_err() {
+        return Err(ParseLiteralError::NotIntegral);
+    }
+    let sign = if negative { "-" } else { "" };
+    T::from_str_radix(&format!("{sign}{whole}.{fraction}"), 10).map_err(|_| ParseLiteralError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaled_si_works() {
+        let cases = [("1k", 1_000), ("1K", 1_000), ("2M", 2_000_000), ("3G", 3_000_000_000), ("1T", 1_000_000_000_000)];
+        for (text, value) in cases {
+            assert_eq!(parse_scaled_literal::<u64>(text), Ok(value), "{text}");
+        }
+        assert_eq!(parse_scaled_literal::<u64>("18E"), Ok(18_000_000_000_000_000_000));
+    }
+
+    #[test]
+    fn scaled_iec_works() {
+        assert_eq!(parse_scaled_literal::<u64>("1Ki"), Ok(1024));
+        assert_eq!(parse_scaled_literal::<u64>("4Mi"), Ok(4 << 20));
+        assert_eq!(parse_scaled_literal::<u64>("1Ei"), Ok(1 << 60));
+    }
+
+    #[test]
+    fn scaled_fraction_works() {
+        assert_eq!(parse_scaled_literal::<u32>("1.5K"), Ok(1500));
+        assert_eq!(parse_scaled_literal::<u32>("1.5Ki"), Ok(1536));
+        assert_eq!(parse_scaled_literal::<u32>("0.25Mi"), Ok(262_144));
+        assert_eq!(parse_scaled_literal::<u32>("2.500k"), Ok(2500));
+        assert_eq!(parse_scaled_literal::<u32>(".5k"), Ok(500));
+        assert_eq!(parse_scaled_literal::<i32>("-1.5M"), Ok(-1_500_000));
+        assert_eq!(parse_scaled_literal::<u32>("1_000.5k"), Ok(1_000_500));
+    }
+
+    #[test]
+    fn scaled_not_integral_fails() {
+        assert_eq!(parse_scaled_literal::<u32>("1.0005K"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parse_scaled_literal::<u32>("0.1Ki"), Err(ParseLiteralError::NotIntegral));
+    }
+
+    #[test]
+    fn scaled_float_works() {
+        assert_eq!(parse_scaled_literal::<f64>("1.5k"), Ok(1500.0));
+        assert_eq!(parse_scaled_literal::<f64>("0.1Ki"), Ok(102.4));
+        assert_eq!(parse_scaled_literal::<f64>("-0.0005k"), Ok(-0.5));
+    }
+
+    #[test]
+    fn scaled_milli_works() {
+        assert_eq!(parse_scaled_literal::<f64>("5m"), Ok(0.005));
+        assert_eq!(parse_scaled_literal::<f64>("5M"), Ok(5_000_000.0));
+        assert_eq!(parse_scaled_literal::<f64>("-2.5m"), Ok(-0.0025));
+        assert_eq!(parse_scaled_literal::<u32>("5000m"), Ok(5));
+        assert_eq!(parse_scaled_literal::<u32>("5m"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parse_scaled_literal::<u32>("5M"), Ok(5_000_000));
+    }
+
+    #[test]
+    fn scaled_unscaled_works() {
+        assert_eq!(parse_scaled_literal::<u32>("0x1E"), Ok(30));
+        assert_eq!(parse_scaled_literal::<u32>(" 42 "), Ok(42));
+    }
+
+    #[test]
+    fn scaled_invalid_fails() {
+        assert_eq!(parse_scaled_literal::<u32>("K"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_scaled_literal::<u32>("-K"), Err(ParseLiteralError::LoneSign));
+        assert_eq!(parse_scaled_literal::<u32>("0x1K"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_scaled_literal::<u32>("1.5.5K"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_scaled_literal::<u32>("5mm"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_scaled_literal::<u32>("-1K"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn scale_table_works() {
+        let table = [("B", 1), ("KiB", 1 << 10), ("MiB", 1 << 20), ("kB", 1_000), ("w", 7)];
+        assert_eq!(parse_with_scale_table::<u64>("4KiB", &table), Ok(4096));
+        assert_eq!(parse_with_scale_table::<u64>("2MiB", &table), Ok(2 << 20));
+        assert_eq!(parse_with_scale_table::<u64>("3kB", &table), Ok(3000));
+        assert_eq!(parse_with_scale_table::<u64>(" 512 B ", &table), Ok(512));
+        assert_eq!(parse_with_scale_table::<u64>("0.5KiB", &table), Ok(512));
+        assert_eq!(parse_with_scale_table::<i64>("-2w", &table), Ok(-14));
+        assert_eq!(parse_with_scale_table::<f64>("1.25kB", &table), Ok(1250.0));
+    }
+
+    #[test]
+    fn scale_table_unscaled_works() {
+        let table = [("KiB", 1 << 10)];
+        assert_eq!(parse_with_scale_table::<u32>("42", &table), Ok(42));
+        assert_eq!(parse_with_scale_table::<u32>("0x1B", &[("B", 1)]), Ok(0x1B));
+        assert_eq!(parse_with_scale_table::<u32>("7", &[]), Ok(7));
+        assert_eq!(parse_with_scale_table::<u32>("7", &[("", 10)]), Ok(7));
+    }
+
+    #[test]
+    fn scale_table_invalid_fails() {
+        let table = [("KiB", 1 << 10), ("B", 1)];
+        assert_eq!(parse_with_scale_table::<u32>("KiB", &table), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_with_scale_table::<u32>("4kib", &table), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_with_scale_table::<u32>("4XB", &table), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_with_scale_table::<u32>("0.3B", &table), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parse_with_scale_table::<u8>("1KiB", &table), Err(ParseLiteralError::Overflow));
+    }
+
+    #[test]
+    fn scaled_value_unit_works() {
+        assert_eq!(parse_scaled_value_unit::<u32>("512KB/s"), Ok((512_000, "/s")));
+        assert_eq!(parse_scaled_value_unit::<u32>("512KiB/s"), Ok((524_288, "/s")));
+        assert_eq!(parse_scaled_value_unit::<u64>("2Mi/1"), Ok((2_097_152, "/1")));
+        assert_eq!(parse_scaled_value_unit::<u32>("1.5k rpm"), Ok((1500, "rpm")));
+        assert_eq!(parse_scaled_value_unit::<u32>("4G"), Ok((4_000_000_000, "")));
+        assert_eq!(parse_scaled_value_unit::<f64>("-2.5MB"), Ok((-2_500_000.0, "")));
+    }
+
+    #[test]
+    fn scaled_value_unit_micro_works() {
+        for text in ["4.7µF", "4.7μF", "4.7uF"] {
+            assert_eq!(parse_scaled_value_unit::<f64>(text), Ok((0.0000047, "F")), "{text}");
+        }
+        assert_eq!(parse_scaled_value_unit::<f64>("-15us"), Ok((-0.000015, "s")));
+        assert_eq!(parse_scaled_value_unit::<f64>("2.2u"), Ok((0.0000022, "")));
+        assert_eq!(parse_scaled_value_unit::<u32>("1000000uF"), Ok((1, "F")));
+        assert_eq!(parse_scaled_value_unit::<u32>("4.7uF"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parse_scaled_value_unit::<f64>("4.7 µF"), Ok((4.7, "µF")));
+    }
+
+    #[test]
+    fn scaled_value_unit_resistance_works() {
+        assert_eq!(parse_scaled_value_unit::<f64>("1.5kΩ"), Ok((1500.0, "Ω")));
+        assert_eq!(parse_scaled_value_unit::<u32>("4.7MΩ"), Ok((4_700_000, "Ω")));
+        assert_eq!(parse_scaled_value_unit::<u32>("2.2k"), Ok((2200, "")));
+        assert_eq!(parse_scaled_value_unit::<f64>("470Ω"), Ok((470.0, "Ω")));
+    }
+
+    #[test]
+    fn scaled_value_unit_unscaled_works() {
+        assert_eq!(parse_scaled_value_unit::<u32>("512 KB/s"), Ok((512, "KB/s")));
+        assert_eq!(parse_scaled_value_unit::<u32>("0x1F MB"), Ok((31, "MB")));
+        assert_eq!(parse_scaled_value_unit::<u32>("0x1FMB"), Ok((0x1F, "MB")));
+        assert_eq!(parse_scaled_value_unit::<u32>("80%"), Ok((80, "%")));
+        assert_eq!(parse_scaled_value_unit::<f64>("1E5m"), Ok((1e5, "m")));
+        assert_eq!(parse_scaled_value_unit::<u32>("5m"), Ok((5, "m")));
+    }
+
+    #[test]
+    fn scaled_value_unit_reads_unit_letters_as_scale() {
+        assert_eq!(parse_scaled_value_unit::<f64>("5Pa"), Ok((5e15, "a")));
+        assert_eq!(parse_scaled_value_unit::<f64>("300K"), Ok((300000.0, "")));
+        assert_eq!(parse_scaled_value_unit::<f64>("5 Pa"), Ok((5.0, "Pa")));
+        assert_eq!(parse_scaled_value_unit::<f64>("300 K"), Ok((300.0, "K")));
+    }
+
+    #[test]
+    fn scaled_value_unit_invalid_fails() {
+        assert_eq!(parse_scaled_value_unit::<u32>("1.5.5k"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_scaled_value_unit::<u32>("1.2345kB"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parse_scaled_value_unit::<u8>("1kB"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_scaled_value_unit::<u32>("KB"), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn basis_points_works() {
+        assert_eq!(parse_basis_points::<u32>("2.5%"), Ok(250));
+        assert_eq!(parse_basis_points::<u32>("100%"), Ok(10_000));
+        assert_eq!(parse_basis_points::<u32>("0.01%"), Ok(1));
+        assert_eq!(parse_basis_points::<u32>(" 12.50 % "), Ok(1250));
+        assert_eq!(parse_basis_points::<i32>("-0.75%"), Ok(-75));
+        assert_eq!(parse_basis_points::<f64>("2.555%"), Ok(255.5));
+    }
+
+    #[test]
+    fn basis_points_not_integral_fails() {
+        assert_eq!(parse_basis_points::<u32>("2.555%"), Err(ParseLiteralError::NotIntegral));
+        assert_eq!(parse_basis_points::<i64>("0.001%"), Err(ParseLiteralError::NotIntegral));
+    }
+
+    #[test]
+    fn basis_points_invalid_fails() {
+        assert_eq!(parse_basis_points::<u32>("2.5"), Err(ParseLiteralError::InvalidUnit));
+        assert_eq!(parse_basis_points::<u32>("%"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_basis_points::<u32>("0x10%"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_basis_points::<u8>("2.56%"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_basis_points::<u32>("-1%"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn scaled_overflow_fails() {
+        assert_eq!(parse_scaled_literal::<u16>("65.536k"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_scaled_literal::<u16>("64Ki"), Err(ParseLiteralError::Overflow));
+        assert_eq!(parse_scaled_literal::<u128>("1000000000000000000000E"), Err(ParseLiteralError::Overflow));
+    }
+}
@@ -0,0 +1,531 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt::{self, Write};
+use crate::ParseLiteralError;
+use crate::constant::split_rust_suffix;
+use crate::format::prefix_of;
+
+/// Unwrap a result in a `const fn`, or return its error. The error type
+/// has a destructor, which constant evaluation cannot run, so the error
+/// is moved out and the result forgotten with nothing left to drop.
+macro_rules! const_try {
+    ($result:expr) => {{
+        let mut result = $result;
+        match &mut result {
+            Ok(value) => {
+                let value = *value;
+                std::mem::forget(result);
+                value
+            },
+            Err(error) => {
+                let error = std::mem::replace(error, ParseLiteralError::Empty);
+                std::mem::forget(result);
+                return Err(error);
+            },
+        }
+    }};
+}
+
+pub(crate) use const_try;
+
+/// Digits that fit the stack buffer of `with_formatted`: a sign and the
+/// 128 digits of a binary `u128`, with room for some leading zeros.
+const BUFFER_LEN: usize = 160;
+
+/// The Unicode spaces that locales use to group digits, accepted in place
+/// of `_` by [`LiteralParser::space_separators`]: the no-break space, the
+/// thin space and the narrow no-break space.
+///
+/// [`LiteralParser::space_separators`]: crate::LiteralParser::space_separators
+pub(crate) const SPACE_SEPARATORS: [char; 3] = ['\u{00A0}', '\u{2009}', '\u{202F}'];
+
+/// The rules of the grammar that `LiteralParser` can change, with the
+/// rules of `parse_literal` as `DEFAULT`.
+///
+/// Every integer literal is read by these rules in two steps, which look
+/// at each byte once and do not allocate: `read_body` reads the sign and
+/// the notations at the end, and `read_digits` the prefix, the digits
+/// and the type suffix in between. Notations of `LiteralParser` that
+/// replace the digits, like inline radixes, look at the body in between.
+/// The prefixes and type suffixes come from `PREFIXES` and
+/// `RUST_SUFFIXES`, so a new one only needs an entry there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Grammar {
+    pub(crate) strict: bool,
+    pub(crate) separators_between_digits: bool,
+    pub(crate) leading_zero_octal: bool,
+    pub(crate) c_suffixes: bool,
+    pub(crate) bigint_suffix: bool,
+    pub(crate) base36_prefix: bool,
+    pub(crate) percent_binary: bool,
+    pub(crate) h_prefix: bool,
+    pub(crate) trailing_sign: bool,
+    pub(crate) space_separators: bool,
+    pub(crate) require_sign: bool,
+    pub(crate) rust_suffixes: bool,
+    /// The group sizes of binary, octal, decimal, hexadecimal and base 36
+    /// digits, or 0 for any grouping.
+    pub(crate) group_sizes: [usize; 5],
+    /// The allowed radixes as bits, with bit 0 for char literals.
+    pub(crate) formats: u64,
+    /// The radix of bare digits without prefix or suffix, which are not
+    /// checked for separators or grouping, or `None` for literals.
+    pub(crate) fixed_radix: Option<u32>,
+}
+
+impl Grammar {
+    /// The grammar of `parse_literal`.
+    pub(crate) const DEFAULT: Self = Self {
+        strict: false,
+        separators_between_digits: false,
+        leading_zero_octal: true,
+        c_suffixes: false,
+        bigint_suffix: false,
+        base36_prefix: true,
+        percent_binary: false,
+        h_prefix: true,
+        trailing_sign: false,
+        space_separators: false,
+        require_sign: false,
+        rust_suffixes: true,
+        group_sizes: [0; 5],
+        formats: u64::MAX,
+        fixed_radix: None,
+    };
+
+    /// Whether literals in the radix, or char literals for `None`, are
+    /// allowed.
+    pub(crate) const fn allows(&self, radix: Option<u32>) -> bool {
+        let bit = match radix {
+            Some(radix) => radix,
+            None => 0,
+        };
+        self.formats & (1 << bit) != 0
+    }
+}
+
+/// The index of the group size of the digits of a radix in
+/// `Grammar::group_sizes`.
+pub(crate) const fn group_index(radix: u32) -> usize {
+    match radix {
+        2 => 0,
+        8 => 1,
+        16 => 3,
+        36 => 4,
+        _ => 2,
+    }
+}
+
+/// A literal with its sign and the notations at its end read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Body {
+    pub(crate) negative: bool,
+    /// The range of the rest of the literal.
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+/// The digits of a literal body, read by `read_digits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Digits {
+    pub(crate) radix: u32,
+    /// The range of the digits and their separators.
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    /// Whether there are separators between the digits.
+    pub(crate) separated: bool,
+    /// The number of digits after the leading zeros.
+    pub(crate) significant: usize,
+    /// The value, or `None` if it does not fit `u128`.
+    pub(crate) magnitude: Option<u128>,
+    /// The range of the type of the Rust suffix, if there is one.
+    pub(crate) suffix: Option<(i128, u128)>,
+}
+
+/// The position of the scanner within the digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// No digit or separator consumed yet.
+    Start,
+    /// After a separator.
+    Separator,
+    /// After a digit, or any other character.
+    Digit,
+}
+
+/// The character that starts at byte `i` of valid UTF-8, and its length.
+const fn char_at(bytes: &[u8], i: usize) -> (char, usize) {
+    let (mut code, len) = match bytes[i] {
+        byte @ 0x00..=0x7F => return (byte as char, 1),
+        byte @ 0xC0..=0xDF => ((byte & 0x1F) as u32, 2),
+        byte @ 0xE0..=0xEF => ((byte & 0x0F) as u32, 3),
+        byte => ((byte & 0x07) as u32, 4),
+    };
+    let mut j = 1;
+    while j < len {
+        code = code << 6 | (bytes[i + j] & 0x3F) as u32;
+        j += 1;
+    }
+    match char::from_u32(code) {
+        Some(chr) => (chr, len),
+        None => (char::REPLACEMENT_CHARACTER, len),
+    }
+}
+
+/// Whether a character separates digits.
+const fn is_separator(chr: char, grammar: &Grammar) -> bool {
+    if chr == '_' {
+        return true;
+    }
+    let mut i = 0;
+    while grammar.space_separators && i < SPACE_SEPARATORS.len() {
+        if SPACE_SEPARATORS[i] == chr {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Read the leading `+` or `-` of a literal, rejecting a sign without
+/// anything after it and signs that are doubled or followed by space.
+/// Returns whether the literal is negative and the length of the sign.
+pub(crate) const fn read_sign(bytes: &[u8]) -> Result<(bool, usize), ParseLiteralError> {
+    let negative = match bytes {
+        [b'-', ..] => true,
+        [b'+', ..] => false,
+        _ => return Ok((false, 0)),
+    };
+    match bytes {
+        [_] => Err(ParseLiteralError::LoneSign),
+        [_, b'+' | b'-', ..] => Err(ParseLiteralError::InvalidSign),
+        _ if char_at(bytes, 1).0.is_whitespace() => Err(ParseLiteralError::InvalidSign),
+        _ => Ok((negative, 1)),
+    }
+}
+
+/// Read the sign of a literal and the notations that the grammar allows
+/// at its end: a trailing sign, a C suffix like `ULL` and the BigInt
+/// suffix `n`, in this order from the end.
+pub(crate) const fn read_body(bytes: &[u8], grammar: &Grammar) -> Result<Body, ParseLiteralError> {
+    let (mut negative, start) = const_try!(read_sign(bytes));
+    let mut signed = start > 0;
+    let mut end = bytes.len();
+    if grammar.trailing_sign && end > start && matches!(bytes[end - 1], b'+' | b'-') {
+        if signed || (end - 1 > start && matches!(bytes[end - 2], b'+' | b'-')) {
+            return Err(ParseLiteralError::InvalidSign);
+        }
+        negative = bytes[end - 1] == b'-';
+        signed = true;
+        end -= 1;
+    }
+    if grammar.require_sign && !signed && end > start {
+        return Err(ParseLiteralError::MissingSign);
+    }
+    if grammar.c_suffixes {
+        end = const_try!(strip_c_suffix(bytes, start, end));
+    }
+    if grammar.bigint_suffix && end > start && bytes[end - 1] == b'n' {
+        end -= 1;
+        // JavaScript only allows the suffix on integers.
+        if is_decimal_float(bytes.split_at(end).0.split_at(start).1) {
+            return Err(ParseLiteralError::InvalidSuffix);
+        }
+    }
+    Ok(Body { negative, start, end })
+}
+
+/// The end of a literal without its C integer suffix like `ULL`, which
+/// may have one `U` before or after the `L`s and must not mix their case.
+const fn strip_c_suffix(bytes: &[u8], start: usize, end: usize) -> Result<usize, ParseLiteralError> {
+    let mut body_end = end;
+    while body_end > start && matches!(bytes[body_end - 1], b'u' | b'U' | b'l' | b'L') {
+        body_end -= 1;
+    }
+    let mut longs_start = body_end;
+    while longs_start < end && matches!(bytes[longs_start], b'u' | b'U') {
+        longs_start += 1;
+    }
+    let mut longs_end = end;
+    while longs_end > longs_start && matches!(bytes[longs_end - 1], b'u' | b'U') {
+        longs_end -= 1;
+    }
+    let unsigned = end - body_end - (longs_end - longs_start);
+    let longs_valid = match longs_end - longs_start {
+        0 | 1 => true,
+        2 => bytes[longs_start] == bytes[longs_start + 1],
+        _ => false,
+    };
+    if unsigned > 1 || !longs_valid {
+        return Err(ParseLiteralError::InvalidSuffix);
+    }
+    Ok(body_end)
+}
+
+/// Whether a body is a decimal number with a point or an exponent, not
+/// one with a prefix, a leading zero or a char literal.
+const fn is_decimal_float(body: &[u8]) -> bool {
+    if let [b'0', _, ..] | [b'\'', _, b'\''] = body {
+        return false;
+    }
+    let mut i = 0;
+    while i < body.len() {
+        if matches!(body[i], b'.' | b'e' | b'E') {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Read the radix prefix, the digits with their separators and the Rust
+/// type suffix of a literal body, and check them with the grammar.
+///
+/// The errors come in a fixed order: a disabled prefix, a leading zero,
+/// a format that is not allowed, misplaced separators, a wrong grouping,
+/// and then missing, signed or invalid digits.
+pub(crate) const fn read_digits(bytes: &[u8], body: &Body, grammar: &Grammar) -> Result<Digits, ParseLiteralError> {
+    let mut end = body.end;
+    let mut start = body.start;
+    let mut radix = 10;
+    let mut prefixed = false;
+    let mut suffix = None;
+    if let Some(fixed_radix) = grammar.fixed_radix {
+        radix = fixed_radix;
+    } else {
+        let text = bytes.split_at(end).0.split_at(start).1;
+        if let Some((prefix, _)) = prefix_of(text) {
+            let letter = prefix.as_bytes()[1];
+            if (letter == b'z' && !grammar.base36_prefix) || (letter == b'h' && !grammar.h_prefix) {
+                return Err(ParseLiteralError::InvalidDigit);
+            }
+        }
+        if grammar.rust_suffixes && let Some((digits, min, max)) = split_rust_suffix(text) {
+            end = start + digits.len();
+            suffix = Some((min, max));
+        }
+        let text = bytes.split_at(end).0.split_at(start).1;
+        if let Some((prefix, format)) = prefix_of(text) {
+            radix = match format.radix() {
+                Some(radix) => radix,
+                None => 10,
+            };
+            start += prefix.len();
+            prefixed = true;
+        } else if let [b'%', ..] = text && grammar.percent_binary {
+            radix = 2;
+            start += 1;
+            prefixed = true;
+        } else if let [b'0', _, ..] = text {
+            if grammar.leading_zero_octal {
+                radix = 8;
+                start += 1;
+                prefixed = true;
+            } else {
+                // Zeros like `00` stay decimal.
+                let mut i = start + 1;
+                while i < end {
+                    let (chr, len) = char_at(bytes, i);
+                    if chr != '0' && !is_separator(chr, grammar) {
+                        return Err(ParseLiteralError::LeadingZero);
+                    }
+                    i += len;
+                }
+            }
+        }
+    }
+    if !grammar.allows(Some(radix)) {
+        return Err(ParseLiteralError::FormatNotAllowed);
+    }
+
+    let group_size = grammar.group_sizes[group_index(radix)];
+    let mut state = State::Start;
+    let mut leading_separator = false;
+    let mut doubled_separator = false;
+    let mut separators = 0;
+    let mut group_len = 0;
+    let mut first_group_len = None;
+    let mut misgrouped = false;
+    let mut first_char = None;
+    let mut invalid = false;
+    let mut significant = 0;
+    let mut magnitude = Some(0u128);
+    let mut i = start;
+    while i < end {
+        let (chr, len) = char_at(bytes, i);
+        i += len;
+        if is_separator(chr, grammar) {
+            match state {
+                State::Start => leading_separator = true,
+                State::Separator => doubled_separator = true,
+                State::Digit => {},
+            }
+            match first_group_len {
+                None => first_group_len = Some(group_len),
+                Some(_) => misgrouped |= group_len != group_size,
+            }
+            state = State::Separator;
+            separators += 1;
+            group_len = 0;
+            continue;
+        }
+        if first_char.is_none() {
+            first_char = Some(chr);
+        }
+        match chr.to_digit(radix) {
+            Some(digit) => {
+                if digit > 0 || significant > 0 {
+                    significant += 1;
+                }
+                magnitude = match magnitude {
+                    Some(value) => match value.checked_mul(radix as u128) {
+                        Some(value) => value.checked_add(digit as u128),
+                        None => None,
+                    },
+                    None => None,
+                };
+            },
+            None => invalid = true,
+        }
+        state = State::Digit;
+        group_len += len;
+    }
+
+    if grammar.fixed_radix.is_none() {
+        let trailing_separator = matches!(state, State::Separator);
+        if grammar.strict && prefixed && leading_separator {
+            return Err(ParseLiteralError::SeparatorAfterPrefix);
+        }
+        // A single separator may follow the prefix.
+        let misplaced = doubled_separator
+            || (leading_separator && !prefixed)
+            || (trailing_separator && !(prefixed && separators == 1 && first_char.is_none()));
+        if (grammar.strict || grammar.separators_between_digits) && misplaced {
+            return Err(ParseLiteralError::MisplacedSeparator);
+        }
+        let first_group_len = match first_group_len {
+            Some(first_group_len) => {
+                misgrouped |= group_len != group_size;
+                first_group_len
+            },
+            None => group_len,
+        };
+        if group_size > 0 && (misgrouped || first_group_len == 0 || first_group_len > group_size) {
+            return Err(ParseLiteralError::InvalidGrouping);
+        }
+    }
+    match first_char {
+        None => Err(ParseLiteralError::Empty),
+        Some('+' | '-') => Err(ParseLiteralError::InvalidSign),
+        Some(_) if invalid => Err(ParseLiteralError::InvalidDigit),
+        Some(_) => Ok(Digits { radix, start, end, separated: separators > 0, significant, magnitude, suffix }),
+    }
+}
+
+/// A text on the stack, for the digits handed to a conversion.
+struct StackText {
+    bytes: [u8; BUFFER_LEN],
+    len: usize,
+}
+
+impl Write for StackText {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let end = self.len + text.len();
+        self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(text.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Call `f` with formatted text, written to the stack unless it is too
+/// long to fit.
+pub(crate) fn with_formatted<R>(args: fmt::Arguments, f: impl FnOnce(&str) -> R) -> R {
+    let mut buffer = StackText { bytes: [0; BUFFER_LEN], len: 0 };
+    match buffer.write_fmt(args) {
+        Ok(()) => f(std::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap_or_default()),
+        Err(_) => f(&args.to_string()),
+    }
+}
+
+/// Digits shown without their separators.
+struct Unseparated<'a> {
+    text: &'a str,
+    space_separators: bool,
+}
+
+impl fmt::Display for Unseparated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.text.split(|chr| chr == '_' || (self.space_separators && SPACE_SEPARATORS.contains(&chr)))
+            .try_for_each(|digits| f.write_str(digits))
+    }
+}
+
+/// Call `f` with digits without their separators `_`, and the spaces of
+/// `SPACE_SEPARATORS` if `space_separators` is set.
+pub(crate) fn with_digits<R>(text: &str, space_separators: bool, f: impl FnOnce(&str) -> R) -> R {
+    with_formatted(format_args!("{}", Unseparated { text, space_separators }), f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(text: &str, grammar: &Grammar) -> Result<Digits, ParseLiteralError> {
+        read_body(text.as_bytes(), grammar).and_then(|body| read_digits(text.as_bytes(), &body, grammar))
+    }
+
+    #[test]
+    fn read_sign_works() {
+        assert_eq!(read_sign(b"-5"), Ok((true, 1)));
+        assert_eq!(read_sign(b"+5"), Ok((false, 1)));
+        assert_eq!(read_sign(b"5-"), Ok((false, 0)));
+        assert_eq!(read_sign(b"-"), Err(ParseLiteralError::LoneSign));
+        for text in ["+-1", "--1", "- 1", "-\u{3000}1"] {
+            assert_eq!(read_sign(text.as_bytes()), Err(ParseLiteralError::InvalidSign), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn read_digits_works() {
+        let digits = read("0x_0F_Fu8", &Grammar::DEFAULT);
+        let expected = Digits {
+            radix: 16,
+            start: 2,
+            end: 7,
+            separated: true,
+            significant: 2,
+            magnitude: Some(0xFF),
+            suffix: Some((0, 255)),
+        };
+        assert_eq!(digits, Ok(expected));
+        let digits = read("-0755", &Grammar::DEFAULT).unwrap();
+        assert_eq!((digits.radix, digits.start, digits.separated, digits.magnitude), (8, 2, false, Some(0o755)));
+        assert_eq!(read(&"1".repeat(40), &Grammar::DEFAULT).map(|digits| digits.magnitude), Ok(None));
+        assert_eq!(read("0zu8", &Grammar::DEFAULT).map(|digits| digits.suffix), Ok(None));
+    }
+
+    #[test]
+    fn read_digits_fixed_radix_works() {
+        let grammar = Grammar { fixed_radix: Some(7), ..Grammar::DEFAULT };
+        let body = Body { negative: false, start: 0, end: 4 };
+        assert_eq!(read_digits(b"1_23", &body, &grammar).map(|digits| digits.magnitude), Ok(Some(66)));
+        assert_eq!(read_digits(b"0x12", &body, &grammar), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(read_digits(b"_-12", &body, &grammar), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(read_digits(b"____", &body, &grammar), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn with_digits_works() {
+        assert_eq!(with_digits("1_000", false, str::to_string), "1000");
+        assert_eq!(with_digits("1\u{202F}000_000", true, str::to_string), "1000000");
+        assert_eq!(with_digits("1\u{202F}000", false, str::to_string), "1\u{202F}000");
+        let long = "1_0".repeat(BUFFER_LEN);
+        assert_eq!(with_digits(&long, false, str::len), 2 * BUFFER_LEN);
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::num::{NonZero, Saturating, Wrapping};
+use num_traits::Num;
+use crate::{LiteralParser, ParseLiteralError};
+use crate::parse_literal::{check_digits, from_digits};
+
+/// Types that an integer literal can be converted to by
+/// [`parse_literal_into`].
+///
+/// The literal is taken apart by the parser, and the type only builds
+/// itself from the sign, the digits and the radix. Each type decides what
+/// a value out of its range means, so wrapper types and third-party
+/// numbers can opt in without changing the parser.
+pub trait LiteralTarget: Sized {
+    /// Build a value from whether the literal is negative and its digits
+    /// in the radix, without prefix, sign or separators, or return an
+    /// error.
+    fn from_literal_parts(negative: bool, digits: &str, radix: u32) -> Result<Self, ParseLiteralError>;
+}
+
+macro_rules! impl_literal_target {
+    ($($t:ty)*) => {$(
+        impl LiteralTarget for $t {
+            fn from_literal_parts(negative: bool, digits: &str, radix: u32) -> Result<Self, ParseLiteralError> {
+                from_digits(negative, digits, radix)
+            }
+        }
+
+        impl LiteralTarget for Wrapping<$t> {
+            fn from_literal_parts(negative: bool, digits: &str, radix: u32) -> Result<Self, ParseLiteralError> {
+                check_digits(digits, radix)?;
+                let value = digits.chars()
+                    .filter_map(|c| c.to_digit(radix))
+                    .fold(Wrapping(0), |value: Self, digit| value * Wrapping(radix as $t) + Wrapping(digit as $t));
+                Ok(if negative { -value } else { value })
+            }
+        }
+
+        impl LiteralTarget for Saturating<$t> {
+            fn from_literal_parts(negative: bool, digits: &str, radix: u32) -> Result<Self, ParseLiteralError> {
+                match from_digits(negative, digits, radix) {
+                    Err(ParseLiteralError::Overflow) if negative => Ok(Saturating(<$t>::MIN)),
+                    Err(ParseLiteralError::Overflow) => Ok(Saturating(<$t>::MAX)),
+                    result => result.map(Saturating),
+                }
+            }
+        }
+
+        impl LiteralTarget for NonZero<$t> {
+            fn from_literal_parts(negative: bool, digits: &str, radix: u32) -> Result<Self, ParseLiteralError> {
+                let value = from_digits(negative, digits, radix)?;
+                NonZero::new(value).ok_or(ParseLiteralError::Zero)
+            }
+        }
+    )*};
+}
+
+impl_literal_target!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Any `num_traits::Num` type as a target, built with its
+/// `from_str_radix`, which `parse_literal` converts to.
+pub(crate) struct NumTarget<T>(pub(crate) T);
+
+impl<T: Num> LiteralTarget for NumTarget<T> {
+    fn from_literal_parts(negative: bool, digits: &str, radix: u32) -> Result<Self, ParseLiteralError> {
+        from_digits(negative, digits, radix).map(Self)
+    }
+}
+
+/// Convert an integer literal to a value of any `LiteralTarget` or return
+/// an error.
+///
+/// The literal follows the grammar of `parse_literal` for integers. How
+/// the value is built is up to the target: primitive integers report an
+/// overflow, `Wrapping` wraps around like its arithmetic, so `"-1"` is
+/// 255 for `Wrapping<u8>`, `Saturating` clamps to its range and `NonZero`
+/// rejects zero.
+///
+/// # Arguments
+/// - `text`: Textual representation of an integer.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::num::{NonZero, Saturating, Wrapping};
+/// use num_literal_traits::{parse_literal_into, ParseLiteralError};
+///
+/// assert_eq!(parse_literal_into::<u8>("0xFF"), Ok(255));
+/// assert_eq!(parse_literal_into::<Wrapping<u8>>("0x1FF"), Ok(Wrapping(255)));
+/// assert_eq!(parse_literal_into::<Saturating<i8>>("-0x1FF"), Ok(Saturating(i8::MIN)));
+/// assert_eq!(parse_literal_into::<NonZero<u32>>("0x0"), Err(ParseLiteralError::Zero));
+/// ```
+pub fn parse_literal_into<T: LiteralTarget>(text: &str) -> Result<T, ParseLiteralError> {
+    LiteralParser::new().parse_into(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NumLiteralTrait;
+
+    const TEXTS: [&str; 32] = [
+        "0", "123", " 42 ", "+7", "-7", "0xCAFE", "0b1010", "0B_1_0", "0o777", "0755", "0zZ9", "'A'", "1_000",
+        "0x", "", "-", "+-1", "- 1", "CAFE", "08", "0x1FF", "-0x80", "-0x81", "-0", "0xFFu8", "0x100u8", "-1u8",
+        "-0x80i8", "0u16", "0zu8", "0u8u8", "0xu8",
+    ];
+
+    #[test]
+    fn primitive_matches_parse_literal() {
+        for text in TEXTS {
+            assert_eq!(parse_literal_into::<u8>(text), u8::parse_literal(text), "{text:?}");
+            assert_eq!(parse_literal_into::<i8>(text), i8::parse_literal(text), "{text:?}");
+            assert_eq!(parse_literal_into::<i64>(text), i64::parse_literal(text), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn wrapping_works() {
+        assert_eq!(parse_literal_into::<Wrapping<u8>>("0x1FF"), Ok(Wrapping(0xFF)));
+        assert_eq!(parse_literal_into::<Wrapping<u8>>("256"), Ok(Wrapping(0)));
+        assert_eq!(parse_literal_into::<Wrapping<u8>>("-1"), Ok(Wrapping(u8::MAX)));
+        assert_eq!(parse_literal_into::<Wrapping<i8>>("0x80"), Ok(Wrapping(i8::MIN)));
+        assert_eq!(parse_literal_into::<Wrapping<i8>>("-0x81"), Ok(Wrapping(i8::MAX)));
+        assert_eq!(parse_literal_into::<Wrapping<u32>>("0zZZZZZZZZZZZZZ"), Ok(Wrapping((36u128.pow(13) - 1) as u32)));
+        let long = format!("0x1{}", "0".repeat(40));
+        assert_eq!(parse_literal_into::<Wrapping<u128>>(&long), Ok(Wrapping(0)));
+    }
+
+    #[test]
+    fn wrapping_malformed_fails() {
+        assert_eq!(parse_literal_into::<Wrapping<u8>>("0x"), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_literal_into::<Wrapping<u8>>("0b12"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_literal_into::<Wrapping<u8>>("--1"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn saturating_works() {
+        assert_eq!(parse_literal_into::<Saturating<u8>>("0x1FF"), Ok(Saturating(u8::MAX)));
+        assert_eq!(parse_literal_into::<Saturating<i8>>("-129"), Ok(Saturating(i8::MIN)));
+        assert_eq!(parse_literal_into::<Saturating<i8>>("-12"), Ok(Saturating(-12)));
+        assert_eq!(parse_literal_into::<Saturating<u8>>("-1"), Err(ParseLiteralError::InvalidSign));
+    }
+
+    #[test]
+    fn non_zero_works() {
+        assert_eq!(parse_literal_into::<NonZero<u8>>("0x10"), Ok(NonZero::new(16).unwrap()));
+        assert_eq!(parse_literal_into::<NonZero<i32>>("-0"), Err(ParseLiteralError::Zero));
+        assert_eq!(parse_literal_into::<NonZero<u8>>("0x100"), Err(ParseLiteralError::Overflow));
+    }
+}
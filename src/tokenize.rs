@@ -0,0 +1,185 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::Range;
+use num_traits::Num;
+use crate::{detect_format, LiteralFormat, NumLiteralTrait};
+use crate::parse_literal::literal_len;
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+/// Iterator over the numeric literals embedded in a text, created by
+/// [`literals`].
+#[derive(Debug, Clone)]
+pub struct Literals<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Iterator for Literals<'_> {
+    type Item = (Range<usize>, LiteralFormat);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        while self.pos < bytes.len() {
+            let start = self.pos;
+            let b = bytes[start];
+            if !(b.is_ascii_digit() || b == b'\'') {
+                // Skip the whole word, so identifiers like `cafe1` never
+                // yield the digits they contain.
+                self.pos += if is_word_byte(b) {
+                    bytes[start..].iter().take_while(|&&b| is_word_byte(b)).count()
+                } else {
+                    self.text[start..].chars().next().map_or(1, char::len_utf8)
+                };
+                continue;
+            }
+
+            let end = start + literal_len(&self.text[start..]);
+            let word_end = end + bytes[end..].iter().take_while(|&&b| is_word_byte(b) || b == b'.').count();
+            self.pos = word_end.max(start + 1);
+            if end > start && word_end == end {
+                return Some((start..end, detect_format(&self.text[start..end])));
+            }
+        }
+        None
+    }
+}
+
+/// Find the numeric literals embedded in a text.
+///
+/// Literals are recognized with the same rules as `parse_literal_prefix`,
+/// but only where they form a whole token: digits inside identifiers, or
+/// literals running into letters like `123abc`, are skipped, as are
+/// floats like `1.5` which are not integer literals.
+///
+/// # Arguments
+/// - `text`: Text to scan, like a line of source code.
+/// # Returns
+/// - Iterator over the byte range and format of each literal.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{literals, LiteralFormat};
+///
+/// let text = "mask = 0xFF | 0b10";
+/// let found: Vec<_> = literals(text).collect();
+/// assert_eq!(found, [
+///     (7..11, LiteralFormat::Hexadecimal),
+///     (14..18, LiteralFormat::Binary),
+/// ]);
+/// ```
+pub fn literals(text: &str) -> Literals<'_> {
+    Literals { text, pos: 0 }
+}
+
+/// Find the first numeric literal embedded in a text that converts to a
+/// number value.
+///
+/// Literals are found like in [`literals`], so words around them and
+/// digits inside words are ignored. A `-` directly before a literal,
+/// unless it follows a word like in `x-1`, makes it negative. A literal
+/// that does not fit `T`, like a negative number for an unsigned type,
+/// is skipped in favor of the next one.
+///
+/// # Arguments
+/// - `text`: Text to scan, like a line of a log.
+/// # Returns
+/// - The value of the first literal, or `None` if there is none.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::extract_first_literal;
+///
+/// assert_eq!(extract_first_literal::<u32>("status 0xFF received"), Some(255));
+/// assert_eq!(extract_first_literal::<i32>("temp: -5 C"), Some(-5));
+/// assert_eq!(extract_first_literal::<u32>("no numbers here"), None);
+/// ```
+pub fn extract_first_literal<T: Num>(text: &str) -> Option<T> {
+    literals(text).find_map(|(range, _)| {
+        let signed = text[..range.start].strip_suffix('-')
+            .filter(|before| !before.bytes().next_back().is_some_and(is_word_byte))
+            .is_some();
+        let start = range.start - usize::from(signed);
+        T::parse_literal(&text[start..range.end]).ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literals_in_code_works() {
+        let text = "let cafe = x[0] + 0x1F * 'A' - (017 >> var2);";
+        let found: Vec<_> = literals(text)
+            .map(|(range, format)| (&text[range], format))
+            .collect();
+        assert_eq!(found, [
+            ("0", LiteralFormat::Decimal),
+            ("0x1F", LiteralFormat::Hexadecimal),
+            ("'A'", LiteralFormat::Char),
+            ("017", LiteralFormat::Octal),
+        ]);
+    }
+
+    #[test]
+    fn literals_with_suffix_work() {
+        let text = "a 0xFFu8 b 12 1u32x 0zu8";
+        let found: Vec<_> = literals(text)
+            .map(|(range, format)| (&text[range], format))
+            .collect();
+        assert_eq!(found, [
+            ("0xFFu8", LiteralFormat::Hexadecimal),
+            ("12", LiteralFormat::Decimal),
+            ("0zu8", LiteralFormat::Base36),
+        ]);
+    }
+
+    #[test]
+    fn literals_skip_words() {
+        let found: Vec<_> = literals("cafe deadbeef 12ab a1 _0 1.5 09").collect();
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn literals_empty_works() {
+        assert_eq!(literals("").next(), None);
+        assert_eq!(literals("全 0b1 全").next(), Some((4..7, LiteralFormat::Binary)));
+    }
+
+    #[test]
+    fn extract_first_in_logs_works() {
+        assert_eq!(extract_first_literal::<u32>("status 0xFF received"), Some(255));
+        assert_eq!(extract_first_literal::<u32>("[2025-01-01 12:00] error"), Some(2025));
+        assert_eq!(extract_first_literal::<u32>("ERR42 code=0b101, retry in 3s"), Some(5));
+        assert_eq!(extract_first_literal::<u32>("dev eth0 mtu 1500"), Some(1500));
+        assert_eq!(extract_first_literal::<u8>("got 'A' (ok)"), Some(65));
+        assert_eq!(extract_first_literal::<u64>("pid=全 4321"), Some(4321));
+    }
+
+    #[test]
+    fn extract_first_signed_works() {
+        assert_eq!(extract_first_literal::<i32>("offset -0x10 bytes"), Some(-16));
+        assert_eq!(extract_first_literal::<i32>("x-1"), Some(1));
+        assert_eq!(extract_first_literal::<i32>("range 3-5"), Some(3));
+        assert_eq!(extract_first_literal::<u32>("delta -5, total 7"), Some(7));
+    }
+
+    #[test]
+    fn extract_first_skips_unfit_literals() {
+        assert_eq!(extract_first_literal::<u8>("read 300 of 0x20"), Some(32));
+        assert_eq!(extract_first_literal::<u8>("read 300 bytes"), None);
+        assert_eq!(extract_first_literal::<u32>("version 1.5 build abc12"), None);
+        assert_eq!(extract_first_literal::<u32>(""), None);
+    }
+}
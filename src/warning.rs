@@ -0,0 +1,121 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use num_traits::Num;
+use crate::{NumLiteralTrait, ParseLiteralError};
+use crate::constant::strip_rust_suffix;
+use crate::format::match_prefix;
+
+/// Literals that parse, but likely not the way the writer meant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LiteralWarning {
+    /// A leading zero selected octal, and the value differs from reading
+    /// the digits as decimal, as in `0123`.
+    AmbiguousOctal,
+}
+
+impl fmt::Display for LiteralWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AmbiguousOctal => f.write_str("leading zero reads the digits as octal, not decimal"),
+        }
+    }
+}
+
+/// Find the warnings for a literal, without converting it.
+///
+/// The text is inspected as `parse_literal` would read it, but a text
+/// that fails to parse can still produce warnings. A leading-zero octal
+/// literal like `0123` is an `AmbiguousOctal` warning, unless it has the
+/// same value as decimal, like `00` or `07`, or uses the `0o` prefix.
+///
+/// # Arguments
+/// - `text`: Textual representation of a number.
+/// # Returns
+/// - The warnings, empty for an unambiguous literal.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{literal_warnings, LiteralWarning};
+///
+/// assert_eq!(literal_warnings("0123"), vec![LiteralWarning::AmbiguousOctal]);
+/// assert_eq!(literal_warnings("0x123"), vec![]);
+/// ```
+pub fn literal_warnings(text: &str) -> Vec<LiteralWarning> {
+    let text = text.trim();
+    let unsigned = strip_rust_suffix(text.strip_prefix(['+', '-']).unwrap_or(text)).0;
+    let mut warnings = Vec::new();
+    if let Some(digits) = unsigned.strip_prefix('0')
+        && match_prefix(unsigned).is_none()
+        && digits.bytes().all(|byte| matches!(byte, b'0'..=b'7' | b'_'))
+        && digits.trim_start_matches(['0', '_']).replace("_", "").len() > 1
+    {
+        warnings.push(LiteralWarning::AmbiguousOctal);
+    }
+    warnings
+}
+
+/// Determine the literal type, then convert to a number value along with
+/// the warnings for the literal, or return an error.
+///
+/// The value is the same as from `parse_literal`, the warnings are those
+/// of `literal_warnings`, so linters can report questionable literals
+/// without changing how they parse.
+///
+/// # Arguments
+/// - `text`: Textual representation of a number.
+/// # Returns
+/// - Numerical result and warnings, or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_literal_checked, LiteralWarning};
+///
+/// assert_eq!(parse_literal_checked::<u32>("0123"), Ok((83, vec![LiteralWarning::AmbiguousOctal])));
+/// assert_eq!(parse_literal_checked::<u32>("0x123"), Ok((291, vec![])));
+/// ```
+pub fn parse_literal_checked<T: Num>(text: &str) -> Result<(T, Vec<LiteralWarning>), ParseLiteralError> {
+    Ok((T::parse_literal(text)?, literal_warnings(text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warnings_ambiguous_octal_works() {
+        for text in ["0123", "010", "-0755", " 0_17 ", "0010", "0123u16", "-010i8"] {
+            assert_eq!(literal_warnings(text), vec![LiteralWarning::AmbiguousOctal], "{text}");
+        }
+    }
+
+    #[test]
+    fn warnings_same_as_decimal_are_none() {
+        for text in ["0", "00", "07", "0_0_7", "123", "0x123", "0o123", "0b101", "0z17", "'0'", "089", "07u8", "0u8"] {
+            assert_eq!(literal_warnings(text), vec![], "{text}");
+        }
+    }
+
+    #[test]
+    fn checked_works() {
+        assert_eq!(parse_literal_checked::<u32>("0123"), Ok((83, vec![LiteralWarning::AmbiguousOctal])));
+        assert_eq!(parse_literal_checked::<u32>("0x123"), Ok((0x123, vec![])));
+        assert_eq!(parse_literal_checked::<i32>("-010"), Ok((-8, vec![LiteralWarning::AmbiguousOctal])));
+        assert_eq!(parse_literal_checked::<u16>("0123u16"), Ok((83, vec![LiteralWarning::AmbiguousOctal])));
+    }
+
+    #[test]
+    fn checked_invalid_fails() {
+        assert_eq!(parse_literal_checked::<u32>("089"), Err(ParseLiteralError::InvalidDigit));
+        assert_eq!(parse_literal_checked::<u8>("0777"), Err(ParseLiteralError::Overflow));
+    }
+}
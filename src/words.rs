@@ -0,0 +1,189 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Num;
+use crate::ParseLiteralError;
+use crate::parse_literal::from_digits;
+
+/// Number words below one hundred and their values.
+const WORDS: [(&str, u128); 28] = [
+    ("zero", 0), ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7),
+    ("eight", 8), ("nine", 9), ("ten", 10), ("eleven", 11), ("twelve", 12), ("thirteen", 13),
+    ("fourteen", 14), ("fifteen", 15), ("sixteen", 16), ("seventeen", 17), ("eighteen", 18),
+    ("nineteen", 19), ("twenty", 20), ("thirty", 30), ("forty", 40), ("fifty", 50), ("sixty", 60),
+    ("seventy", 70), ("eighty", 80), ("ninety", 90),
+];
+
+/// Scale words and their multipliers.
+const SCALES: [(&str, u128); 4] = [
+    ("thousand", 1_000),
+    ("million", 1_000_000),
+    ("billion", 1_000_000_000),
+    ("trillion", 1_000_000_000_000),
+];
+
+/// The kind of the previous word, which decides what may follow it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Word {
+    None,
+    Ones,
+    Teen,
+    Tens,
+    Hundred,
+    Scale,
+}
+
+/// Convert a cardinal number in English words, like `forty two`, to a
+/// number value or return an error.
+///
+/// The words are separated by whitespace or hyphens and read in any
+/// case: `zero` to `nineteen`, the tens `twenty` to `ninety`, `hundred`
+/// and the scales `thousand`, `million`, `billion` and `trillion`, each
+/// scale at most once and in decreasing order. An `and` may appear
+/// anywhere and is ignored, and a leading `minus` makes the number
+/// negative. A word that is not one of these is an `UnknownWord` error
+/// naming it, while known words in an order that does not spell a
+/// number, like `two three` or `thousand million`, are an
+/// `InvalidNumeral` error.
+///
+/// # Arguments
+/// - `text`: Number in English words.
+/// # Returns
+/// - Numerical result or error.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_literal_traits::{parse_words, ParseLiteralError};
+///
+/// assert_eq!(parse_words::<u32>("forty-two"), Ok(42));
+/// assert_eq!(parse_words::<u32>("one hundred and twenty three"), Ok(123));
+/// assert_eq!(parse_words::<i32>("minus two thousand five"), Ok(-2005));
+/// assert_eq!(
+///     parse_words::<u32>("forty tow"),
+///     Err(ParseLiteralError::UnknownWord { word: "tow".to_string() }),
+/// );
+/// ```
+pub fn parse_words<T: Num>(text: &str) -> Result<T, ParseLiteralError> {
+    let text = text.to_lowercase();
+    let mut words = text.split(|c: char| c.is_whitespace() || c == '-').filter(|word| !word.is_empty()).peekable();
+    let negative = words.next_if_eq(&"minus").is_some();
+    let mut total = 0u128;
+    let mut current = 0u128;
+    let mut last = Word::None;
+    let mut last_scale = u128::MAX;
+    let mut any_word = false;
+    for word in words {
+        if word == "and" {
+            continue;
+        }
+        let (kind, value) = if let Some(&(_, value)) = WORDS.iter().find(|(name, _)| *name == word) {
+            match value {
+                0..10 => (Word::Ones, value),
+                10..20 => (Word::Teen, value),
+                _ => (Word::Tens, value),
+            }
+        } else if word == "hundred" {
+            (Word::Hundred, 100)
+        } else if let Some(&(_, value)) = SCALES.iter().find(|(name, _)| *name == word) {
+            (Word::Scale, value)
+        } else {
+            return Err(ParseLiteralError::UnknownWord { word: word.to_string() });
+        };
+        let follows = match kind {
+            Word::Ones => matches!(last, Word::None | Word::Tens | Word::Hundred | Word::Scale),
+            Word::Teen | Word::Tens => matches!(last, Word::None | Word::Hundred | Word::Scale),
+            Word::Hundred => matches!(last, Word::Ones | Word::Teen | Word::Tens) && current < 100,
+            Word::Scale => last != Word::None && last != Word::Scale && value < last_scale,
+            Word::None => unreachable!(),
+        };
+        // A `zero` has to stand alone, it is the only word leaving zero.
+        if !follows || (value == 0 && any_word) || (last == Word::Ones && current == 0) {
+            return Err(ParseLiteralError::InvalidNumeral);
+        }
+        match kind {
+            Word::Hundred => current *= 100,
+            Word::Scale => {
+                total = current.checked_mul(value).and_then(|value| total.checked_add(value))
+                    .ok_or(ParseLiteralError::Overflow)?;
+                current = 0;
+                last_scale = value;
+            },
+            _ => current += value,
+        }
+        last = kind;
+        any_word = true;
+    }
+    if !any_word {
+        return Err(if negative { ParseLiteralError::LoneSign } else { ParseLiteralError::Empty });
+    }
+    let total = total.checked_add(current).ok_or(ParseLiteralError::Overflow)?;
+    from_digits(negative, &total.to_string(), 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_works() {
+        let cases = [
+            ("zero", 0),
+            ("seven", 7),
+            ("thirteen", 13),
+            ("forty two", 42),
+            ("Forty-Two", 42),
+            ("one hundred twenty three", 123),
+            ("one hundred and twenty three", 123),
+            ("nine hundred ninety nine", 999),
+            ("twelve hundred", 1200),
+            ("one thousand", 1000),
+            ("two thousand and five", 2005),
+            ("three thousand four hundred fifty six", 3456),
+            ("nineteen hundred eighty four", 1984),
+            ("one million two hundred thousand", 1_200_000),
+            ("  sixty\tfour  ", 64),
+        ];
+        for (text, value) in cases {
+            assert_eq!(parse_words::<u32>(text), Ok(value), "{text}");
+        }
+    }
+
+    #[test]
+    fn words_negative_works() {
+        assert_eq!(parse_words::<i32>("minus forty two"), Ok(-42));
+        assert_eq!(parse_words::<u32>("minus forty two"), Err(ParseLiteralError::InvalidSign));
+        assert_eq!(parse_words::<i32>("minus"), Err(ParseLiteralError::LoneSign));
+    }
+
+    #[test]
+    fn words_unknown_word_fails() {
+        let error = parse_words::<u32>("one hundred twenty tree").unwrap_err();
+        assert_eq!(error, ParseLiteralError::UnknownWord { word: "tree".to_string() });
+        assert_eq!(error.to_string(), "unknown number word `tree`");
+        assert_eq!(parse_words::<u32>("42"), Err(ParseLiteralError::UnknownWord { word: "42".to_string() }));
+    }
+
+    #[test]
+    fn words_misordered_fails() {
+        for text in [
+            "two three", "twenty thirty", "twenty eleven", "thousand", "one thousand one million",
+            "one thousand thousand", "zero one", "one zero", "one hundred hundred", "hundred",
+        ] {
+            assert_eq!(parse_words::<u32>(text), Err(ParseLiteralError::InvalidNumeral), "{text}");
+        }
+        assert_eq!(parse_words::<u32>(""), Err(ParseLiteralError::Empty));
+        assert_eq!(parse_words::<u32>("and"), Err(ParseLiteralError::Empty));
+    }
+
+    #[test]
+    fn words_overflow_fails() {
+        assert_eq!(parse_words::<u8>("two hundred fifty five"), Ok(255));
+        assert_eq!(parse_words::<u8>("two hundred fifty six"), Err(ParseLiteralError::Overflow));
+    }
+}
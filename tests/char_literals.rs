@@ -0,0 +1,45 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Exhaustive tests of char literals: a single ASCII character between
+//! single quotes converts to its byte value, anything else is rejected.
+
+use num_literal_traits::{format_literal, LiteralFormat, NumLiteralTrait, ParseLiteralError};
+
+#[test]
+fn char_printable_ascii_works() {
+    for byte in 0x20u8..=0x7E {
+        let text = format!("'{}'", char::from(byte));
+        assert_eq!(u32::parse_literal(&text), Ok(u32::from(byte)), "{text}");
+        assert_eq!(u8::parse_literal(&text), Ok(byte), "{text}");
+        assert_eq!(format_literal(byte, LiteralFormat::Char), text);
+    }
+}
+
+#[test]
+fn char_control_ascii_works() {
+    for byte in (0x00u8..0x20).chain([0x7F]) {
+        let text = format!("'{}'", char::from(byte));
+        assert_eq!(u32::parse_literal(&text), Ok(u32::from(byte)), "{text:?}");
+    }
+}
+
+#[test]
+fn char_non_ascii_fails() {
+    for chr in '\u{80}'..=char::MAX {
+        let text = format!("'{chr}'");
+        assert_eq!(u32::parse_literal(&text), Err(ParseLiteralError::InvalidDigit), "{text}");
+    }
+}
+
+#[test]
+fn char_not_single_fails() {
+    for text in ["''", "'AB'", "'A", "A'"] {
+        assert_eq!(u32::parse_literal(text), Err(ParseLiteralError::InvalidDigit), "{text}");
+    }
+}
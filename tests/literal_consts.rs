@@ -0,0 +1,15 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/literal_consts_valid.rs");
+    cases.compile_fail("tests/ui/literal_consts_invalid.rs");
+    cases.compile_fail("tests/ui/literal_consts_type.rs");
+}
@@ -0,0 +1,124 @@
+// Copyright 2025 Sascha Klick
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Property tests checking that every formatted literal parses back into
+//! the value it was formatted from.
+
+use num_literal_traits::{
+    detect_format, format_literal, format_literal_with, FormatOptions, Literal, LiteralFormat, LiteralParser,
+    NumLiteralTrait,
+};
+use proptest::prelude::*;
+
+fn any_format() -> impl Strategy<Value = LiteralFormat> {
+    prop_oneof![
+        Just(LiteralFormat::Binary),
+        Just(LiteralFormat::Octal),
+        Just(LiteralFormat::Decimal),
+        Just(LiteralFormat::Hexadecimal),
+        Just(LiteralFormat::Base36),
+        Just(LiteralFormat::Char),
+    ]
+}
+
+/// Options with a prefix, so that the output always parses back.
+fn any_options() -> impl Strategy<Value = FormatOptions> {
+    (any::<bool>(), any::<bool>(), prop::array::uniform5(0usize..8)).prop_map(|(uppercase, grouping, sizes)| {
+        let formats = [
+            LiteralFormat::Binary,
+            LiteralFormat::Octal,
+            LiteralFormat::Decimal,
+            LiteralFormat::Hexadecimal,
+            LiteralFormat::Base36,
+        ];
+        formats.into_iter().zip(sizes).fold(
+            FormatOptions::new().uppercase(uppercase).grouping(grouping),
+            |options, (format, size)| options.group_size(format, size),
+        )
+    })
+}
+
+/// Values near the printable ASCII range, to exercise char literals.
+fn small_value() -> impl Strategy<Value = i64> {
+    -200i64..200
+}
+
+/// The format a literal was written in, given that zero in octal and
+/// values without a printable char are written as decimal.
+fn written_format(value: u128, format: LiteralFormat) -> LiteralFormat {
+    match format {
+        LiteralFormat::Octal if value == 0 => LiteralFormat::Decimal,
+        LiteralFormat::Char if !(0x20..0x7F).contains(&value) => LiteralFormat::Decimal,
+        format => format,
+    }
+}
+
+proptest! {
+    #[test]
+    fn round_trip_u128_works(value in any::<u128>(), format in any_format(), options in any_options()) {
+        let text = format_literal_with(value, format, options);
+        prop_assert_eq!(u128::parse_literal(&text), Ok(value), "{}", text);
+    }
+
+    #[test]
+    fn round_trip_i128_works(value in any::<i128>(), format in any_format(), options in any_options()) {
+        let text = format_literal_with(value, format, options);
+        prop_assert_eq!(i128::parse_literal(&text), Ok(value), "{}", text);
+    }
+
+    #[test]
+    fn round_trip_i64_works(value in any::<i64>(), format in any_format()) {
+        let text = format_literal(value, format);
+        prop_assert_eq!(i64::parse_literal(&text), Ok(value), "{}", text);
+    }
+
+    #[test]
+    fn round_trip_small_works(value in small_value(), format in any_format(), options in any_options()) {
+        let text = format_literal_with(value, format, options);
+        prop_assert_eq!(i64::parse_literal(&text), Ok(value), "{}", text);
+    }
+
+    #[test]
+    fn round_trip_u8_works(value in any::<u8>(), format in any_format(), options in any_options()) {
+        let text = format_literal_with(value, format, options);
+        prop_assert_eq!(u8::parse_literal(&text), Ok(value), "{}", text);
+    }
+
+    #[test]
+    fn round_trip_strict_works(value in any::<i64>(), format in any_format(), options in any_options()) {
+        let text = format_literal_with(value, format, options);
+        prop_assert_eq!(LiteralParser::new().strict(true).parse::<i64>(&text), Ok(value), "{}", text);
+    }
+
+    #[test]
+    fn round_trip_format_works(value in any::<u64>(), format in any_format(), options in any_options()) {
+        let text = format_literal_with(value, format, options);
+        prop_assert_eq!(detect_format(&text), written_format(value.into(), format), "{}", text);
+    }
+
+    #[test]
+    fn round_trip_negative_format_works(value in i64::MIN..0, format in any_format()) {
+        let text = format_literal(value, format);
+        let unsigned = text.strip_prefix('-').unwrap();
+        prop_assert_eq!(detect_format(unsigned), written_format(value.unsigned_abs().into(), format), "{}", text);
+    }
+
+    #[test]
+    fn round_trip_details_works(value in any::<i32>(), format in any_format()) {
+        let text = format_literal(value, format);
+        let details = i32::parse_literal_details(&text).unwrap();
+        prop_assert_eq!(details.value, value);
+        prop_assert_eq!(details.format, written_format(value.unsigned_abs().into(), format));
+    }
+
+    #[test]
+    fn round_trip_literal_works(value in any::<i128>()) {
+        let text = Literal(value).to_string();
+        prop_assert_eq!(text.parse::<Literal<i128>>(), Ok(Literal(value)));
+    }
+}
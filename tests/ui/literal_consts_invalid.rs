@@ -0,0 +1,10 @@
+use num_literal_traits::literal_consts;
+
+literal_consts! {
+    const MASK: u32 = "0xFF00";
+    const MAGIC: u32 = "0xCAFG";
+}
+
+fn main() {
+    let _ = (MASK, MAGIC);
+}
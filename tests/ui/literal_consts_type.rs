@@ -0,0 +1,9 @@
+use num_literal_traits::literal_consts;
+
+literal_consts! {
+    const RATIO: f32 = "1";
+}
+
+fn main() {
+    let _ = RATIO;
+}
@@ -0,0 +1,18 @@
+use num_literal_traits::literal_consts;
+
+literal_consts! {
+    /// The high byte of a word.
+    pub const MASK: u32 = "0xFF00";
+    const MODE: u32 = "0755";
+    const FLAGS: u8 = "0b1000_0001";
+    const LETTER: u8 = "'A'";
+    const MIN: i8 = "-0x80";
+    const MAX: u128 = "340282366920938463463374607431768211455";
+}
+
+fn main() {
+    assert_eq!([MASK, MODE], [0xFF00, 0o755]);
+    assert_eq!([FLAGS, LETTER], [0b1000_0001, 65]);
+    assert_eq!(MIN, i8::MIN);
+    assert_eq!(MAX, u128::MAX);
+}
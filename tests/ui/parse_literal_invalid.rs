@@ -0,0 +1,7 @@
+use num_literal_traits::parse_literal;
+
+const MAGIC: u32 = parse_literal!("0xCAFG" as u32);
+
+fn main() {
+    let _ = MAGIC;
+}
@@ -0,0 +1,5 @@
+use num_literal_traits::parse_literal;
+
+fn main() {
+    let _ = parse_literal!("0x1FF" as u8);
+}
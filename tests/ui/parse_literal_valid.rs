@@ -0,0 +1,17 @@
+use num_literal_traits::parse_literal;
+
+const TABLE: [u32; 4] = [
+    parse_literal!("0xCAFE" as u32),
+    parse_literal!("0b1000_0001" as u32),
+    parse_literal!("0755" as u32),
+    parse_literal!("'A'" as u32),
+];
+
+fn main() {
+    assert_eq!(TABLE, [0xCAFE, 0b1000_0001, 0o755, 65]);
+    assert_eq!(parse_literal!("-0x80" as i8), i8::MIN);
+    assert_eq!(parse_literal!("340282366920938463463374607431768211455" as u128), u128::MAX);
+    assert_eq!(parse_literal!("-0x8000_0000_0000_0000_0000_0000_0000_0000" as i128), i128::MIN);
+    assert_eq!(parse_literal!(" 0xFFu8 " as u32), 255);
+    assert_eq!(parse_literal!("0zu8" as u32), 1088);
+}